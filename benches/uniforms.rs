@@ -0,0 +1,253 @@
+/*!
+per-draw cost of setting uniforms, comparing the locations cached in [`Shader`] against
+looking every location up with `glGetUniformLocation` on every draw, like draws used to.
+
+runs on a hidden context from mesa's surfaceless EGL platform, and skips itself if there's
+no EGL to create one with.
+
+```sh
+cargo bench --bench uniforms
+```
+*/
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use lykoi_drive::gl;
+use lykoi_drive::{Config, Draw, Shader, Texture, UniformItem, VertexBuffer, VertexFormat};
+
+
+const VERTEX: &str = "
+#version 330 core
+layout(location = 0) in vec2 a_pos;
+uniform mat4 u_view;
+uniform vec2 u_offset;
+uniform float u_scale;
+void main() {
+	gl_Position = u_view * vec4(a_pos * u_scale + u_offset, 0.0, 1.0);
+}
+";
+const FRAGMENT: &str = "
+#version 330 core
+uniform vec4 u_color;
+uniform vec4 u_tint;
+uniform float u_alpha;
+uniform int u_mode;
+uniform vec3 u_light;
+out vec4 o_color;
+void main() {
+	vec4 color = u_mode == 0 ? u_color : u_tint;
+	o_color = vec4(color.rgb * u_light, color.a * u_alpha);
+}
+";
+const NAMES: [&str; 8] = ["u_view", "u_offset", "u_scale", "u_color", "u_tint", "u_alpha", "u_mode", "u_light"];
+
+const ITERATIONS: u32 = 20_000;
+
+
+fn main() {
+	let Some(_context) = egl::Context::new() else {
+		println!("skipped: no EGL context available");
+		return;
+	};
+	gl::raw::load_with(egl::get_proc_address);
+
+	let shader = Shader::new_src(VERTEX, FRAGMENT).unwrap();
+	let format = VertexFormat::new().field_vec2();
+	let vertex = VertexBuffer::new_from(&[[0.0f32, 0.0], [1.0, 0.0], [0.0, 1.0]], &format).unwrap();
+	let target = Texture::new_empty(64, 64);
+	let mut draw = Draw::new(64, 64);
+
+	let view: cgmath::Matrix4<f32> = cgmath::Matrix4::from_scale(0.5);
+	let uniforms = || UniformItem::new()
+		.add("u_view", &view)
+		.add("u_offset", [0.25f32, 0.25])
+		.add("u_scale", 1.0f32)
+		.add("u_color", [1.0f32, 0.0, 0.0, 1.0])
+		.add("u_tint", [0.0f32, 1.0, 0.0, 1.0])
+		.add("u_alpha", 0.5f32)
+		.add("u_mode", 1i32)
+		.add("u_light", [1.0f32, 1.0, 1.0]);
+
+	// the lookup alone, which is what caching the locations removes from every draw.
+	report("lookup, glGetUniformLocation", bench(|| {
+		for name in NAMES {
+			black_box(gl::get_uniform_location(shader.handle(), black_box(name)));
+		}
+	}));
+	report("lookup, cached in Shader", bench(|| {
+		for name in NAMES {
+			black_box(shader.get_uniform(black_box(name)).map(|v| v.location));
+		}
+	}));
+
+	// a whole draw, with and without the lookups the old path did on top.
+	report("draw, with glGetUniformLocation", bench(|| {
+		for name in NAMES {
+			black_box(gl::get_uniform_location(shader.handle(), black_box(name)));
+		}
+		draw.draw(Some(target.surface()), &vertex, &shader, uniforms(), Config::new());
+	}));
+	report("draw, cached", bench(|| {
+		draw.draw(Some(target.surface()), &vertex, &shader, uniforms(), Config::new());
+	}));
+}
+
+fn bench(mut f: impl FnMut()) -> Duration {
+	for _ in 0..ITERATIONS / 10 {
+		f();
+	}
+	finish();
+
+	let start = Instant::now();
+	for _ in 0..ITERATIONS {
+		f();
+	}
+	finish();
+	start.elapsed() / ITERATIONS
+}
+
+fn finish() {
+	unsafe {
+		gl::raw::Finish();
+	}
+}
+
+fn report(name: &str, time: Duration) {
+	println!("{:<36} {:>8} ns/iter", name, time.as_nanos());
+}
+
+
+/// just enough EGL to make a hidden context current, loaded at runtime so the bench still
+/// builds without it.
+#[cfg(target_os = "linux")]
+mod egl {
+	use std::ffi::{c_char, c_int, c_void, CString};
+	use std::sync::OnceLock;
+
+	const RTLD_NOW: c_int = 2;
+
+	const NONE: i32 = 0x3038;
+	const PLATFORM_SURFACELESS_MESA: u32 = 0x31DD;
+	const SURFACE_TYPE: i32 = 0x3033;
+	const PBUFFER_BIT: i32 = 0x0001;
+	const RENDERABLE_TYPE: i32 = 0x3040;
+	const OPENGL_BIT: i32 = 0x0008;
+	const OPENGL_API: u32 = 0x30A2;
+	const CONTEXT_MAJOR_VERSION: i32 = 0x3098;
+	const CONTEXT_MINOR_VERSION: i32 = 0x30FB;
+	const CONTEXT_OPENGL_PROFILE_MASK: i32 = 0x30FD;
+	const CONTEXT_OPENGL_CORE_PROFILE_BIT: i32 = 0x0001;
+
+	#[link(name = "dl")]
+	unsafe extern "C" {
+		fn dlopen(filename: *const c_char, flags: c_int) -> *mut c_void;
+		fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+	}
+
+	type GetProcAddress = unsafe extern "C" fn(*const c_char) -> *const c_void;
+	type GetPlatformDisplay = unsafe extern "C" fn(u32, *mut c_void, *const isize) -> *mut c_void;
+	type Initialize = unsafe extern "C" fn(*mut c_void, *mut i32, *mut i32) -> u32;
+	type ChooseConfig = unsafe extern "C" fn(*mut c_void, *const i32, *mut *mut c_void, i32, *mut i32) -> u32;
+	type BindApi = unsafe extern "C" fn(u32) -> u32;
+	type CreateContext = unsafe extern "C" fn(*mut c_void, *mut c_void, *mut c_void, *const i32) -> *mut c_void;
+	type MakeCurrent = unsafe extern "C" fn(*mut c_void, *mut c_void, *mut c_void, *mut c_void) -> u32;
+
+	struct Egl {
+		get_proc_address: GetProcAddress,
+		get_platform_display: GetPlatformDisplay,
+		initialize: Initialize,
+		choose_config: ChooseConfig,
+		bind_api: BindApi,
+		create_context: CreateContext,
+		make_current: MakeCurrent,
+	}
+
+	static EGL: OnceLock<Option<Egl>> = OnceLock::new();
+
+	fn load() -> Option<&'static Egl> {
+		EGL.get_or_init(|| unsafe {
+			let lib = dlopen(c"libEGL.so.1".as_ptr(), RTLD_NOW);
+			if lib.is_null() {
+				return None;
+			}
+			let sym = |name: &str| {
+				let name = CString::new(name).unwrap();
+				let out = dlsym(lib, name.as_ptr());
+				(!out.is_null()).then_some(out)
+			};
+			Some(Egl {
+				get_proc_address: std::mem::transmute::<*mut c_void, GetProcAddress>(sym("eglGetProcAddress")?),
+				get_platform_display: std::mem::transmute::<*mut c_void, GetPlatformDisplay>(sym("eglGetPlatformDisplay")?),
+				initialize: std::mem::transmute::<*mut c_void, Initialize>(sym("eglInitialize")?),
+				choose_config: std::mem::transmute::<*mut c_void, ChooseConfig>(sym("eglChooseConfig")?),
+				bind_api: std::mem::transmute::<*mut c_void, BindApi>(sym("eglBindAPI")?),
+				create_context: std::mem::transmute::<*mut c_void, CreateContext>(sym("eglCreateContext")?),
+				make_current: std::mem::transmute::<*mut c_void, MakeCurrent>(sym("eglMakeCurrent")?),
+			})
+		}).as_ref()
+	}
+
+	/// a current OpenGL 3.3 core context without a surface. it's never destroyed, the bench
+	/// exits right after it's done with it.
+	pub struct Context;
+	impl Context {
+		pub fn new() -> Option<Self> {
+			let egl = load()?;
+			unsafe {
+				let display = (egl.get_platform_display)(PLATFORM_SURFACELESS_MESA, std::ptr::null_mut(), std::ptr::null());
+				if display.is_null() || (egl.initialize)(display, std::ptr::null_mut(), std::ptr::null_mut()) == 0 {
+					return None;
+				}
+
+				// the surfaceless platform has no window configs, which are the default.
+				let attribs = [SURFACE_TYPE, PBUFFER_BIT, RENDERABLE_TYPE, OPENGL_BIT, NONE];
+				let mut config = std::ptr::null_mut();
+				let mut count = 0;
+				if (egl.choose_config)(display, attribs.as_ptr(), &mut config, 1, &mut count) == 0 || count == 0 {
+					return None;
+				}
+				if (egl.bind_api)(OPENGL_API) == 0 {
+					return None;
+				}
+
+				let attribs = [
+					CONTEXT_MAJOR_VERSION, 3,
+					CONTEXT_MINOR_VERSION, 3,
+					CONTEXT_OPENGL_PROFILE_MASK, CONTEXT_OPENGL_CORE_PROFILE_BIT,
+					NONE,
+				];
+				let context = (egl.create_context)(display, config, std::ptr::null_mut(), attribs.as_ptr());
+				if context.is_null() {
+					return None;
+				}
+				if (egl.make_current)(display, std::ptr::null_mut(), std::ptr::null_mut(), context) == 0 {
+					return None;
+				}
+			}
+			Some(Context)
+		}
+	}
+
+	pub fn get_proc_address(name: &str) -> *const c_void {
+		let Some(egl) = load() else {
+			return std::ptr::null();
+		};
+		let name = CString::new(name).unwrap();
+		unsafe { (egl.get_proc_address)(name.as_ptr()) }
+	}
+}
+
+#[cfg(not(target_os = "linux"))]
+mod egl {
+	pub struct Context;
+	impl Context {
+		pub fn new() -> Option<Self> {
+			None
+		}
+	}
+
+	pub fn get_proc_address(_: &str) -> *const std::ffi::c_void {
+		std::ptr::null()
+	}
+}
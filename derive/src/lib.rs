@@ -0,0 +1,39 @@
+/*!
+derive macros for [`lykoi_drive`](https://docs.rs/lykoi_drive).
+
+don't depend on this crate directly, enable the `derive` feature of `lykoi_drive` instead.
+*/
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod std140;
+mod uniform;
+mod vertex;
+
+/// implements `VertexLayout` for a struct, see `lykoi_drive::VertexLayout`.
+#[proc_macro_derive(VertexLayout, attributes(vertex))]
+pub fn derive_vertex_layout(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	vertex::derive(input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+/// implements `UniformList` for a struct, see `lykoi_drive::UniformList`.
+#[proc_macro_derive(Uniforms, attributes(uniform))]
+pub fn derive_uniforms(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	uniform::derive(input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
+
+/// implements `Std140` for a struct, see `lykoi_drive::Std140`.
+#[proc_macro_derive(Std140, attributes(std140))]
+pub fn derive_std140(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	std140::derive(input)
+		.unwrap_or_else(|e| e.to_compile_error())
+		.into()
+}
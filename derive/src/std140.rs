@@ -0,0 +1,104 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Result};
+
+
+fn is_padding(field: &syn::Field) -> Result<bool> {
+	let mut padding = false;
+	for attr in &field.attrs {
+		if !attr.path().is_ident("std140") {
+			continue;
+		}
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("padding") {
+				padding = true;
+				Ok(())
+			} else {
+				Err(meta.error("expected `padding`"))
+			}
+		})?;
+	}
+	Ok(padding)
+}
+
+fn is_repr_c(input: &DeriveInput) -> Result<bool> {
+	let mut repr_c = false;
+	for attr in &input.attrs {
+		if !attr.path().is_ident("repr") {
+			continue;
+		}
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("C") {
+				repr_c = true;
+			}
+			Ok(())
+		})?;
+	}
+	Ok(repr_c)
+}
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+	let Data::Struct(data) = &input.data else {
+		return Err(Error::new_spanned(&input.ident, "`Std140` can only be derived for structs"));
+	};
+	let Fields::Named(fields) = &data.fields else {
+		return Err(Error::new_spanned(&input.ident, "`Std140` needs named fields"));
+	};
+	if !input.generics.params.is_empty() {
+		return Err(Error::new_spanned(&input.generics, "`Std140` can't be derived for generic structs"));
+	}
+	if !is_repr_c(&input)? {
+		return Err(Error::new_spanned(&input.ident, "`Std140` needs `#[repr(C)]`"));
+	}
+
+	let name = &input.ident;
+
+	let mut types = Vec::new();
+	let mut fields_std140 = Vec::new();
+	let mut fields_padding = Vec::new();
+	let mut asserts = Vec::new();
+
+	for field in &fields.named {
+		let ident = field.ident.as_ref().unwrap();
+		let ty = &field.ty;
+		types.push(ty);
+
+		if is_padding(field)? {
+			fields_padding.push(ty);
+			continue;
+		}
+		fields_std140.push(ty);
+
+		let message = format!("field `{}` isn't aligned to its std140 alignment", ident);
+		asserts.push(quote! {
+			assert!(
+				::core::mem::offset_of!(#name, #ident) % <#ty as ::lykoi_drive::Std140>::ALIGN == 0,
+				#message,
+			);
+		});
+	}
+
+	Ok(quote! {
+		const _: () = {
+			fn assert_std140<T: ::lykoi_drive::Std140>() {}
+			fn assert_pod<T: ::lykoi_drive::Pod>() {}
+			fn assert_fields() {
+				#(assert_std140::<#fields_std140>();)*
+				#(assert_pod::<#fields_padding>();)*
+			}
+			#(#asserts)*
+			assert!(
+				::core::mem::size_of::<#name>() == 0 #(+ ::core::mem::size_of::<#types>())*,
+				"std140 struct has implicit padding, write it out as `#[std140(padding)]` fields",
+			);
+			assert!(
+				::core::mem::size_of::<#name>() % 16 == 0,
+				"std140 struct size has to be a multiple of 16 bytes",
+			);
+		};
+		unsafe impl ::lykoi_drive::Pod for #name {}
+		unsafe impl ::lykoi_drive::Std140 for #name {
+			const ALIGN: usize = 16;
+		}
+	})
+}
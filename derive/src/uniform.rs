@@ -0,0 +1,67 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, LitStr, Result};
+
+
+#[derive(Default)]
+struct FieldOptions {
+	name: Option<String>,
+	skip: bool,
+}
+
+fn field_options(field: &syn::Field) -> Result<FieldOptions> {
+	let mut options = FieldOptions::default();
+	for attr in &field.attrs {
+		if !attr.path().is_ident("uniform") {
+			continue;
+		}
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("name") {
+				let lit: LitStr = meta.value()?.parse()?;
+				options.name = Some(lit.value());
+			} else if meta.path.is_ident("skip") {
+				options.skip = true;
+			} else {
+				return Err(meta.error("expected `name = \"...\"` or `skip`"));
+			}
+			Ok(())
+		})?;
+	}
+	Ok(options)
+}
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+	let Data::Struct(data) = &input.data else {
+		return Err(Error::new_spanned(&input.ident, "`Uniforms` can only be derived for structs"));
+	};
+	let Fields::Named(fields) = &data.fields else {
+		return Err(Error::new_spanned(&input.ident, "`Uniforms` needs named fields"));
+	};
+
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+	let mut calls = Vec::new();
+
+	for field in &fields.named {
+		let options = field_options(field)?;
+		if options.skip {
+			continue;
+		}
+		let ident = field.ident.as_ref().unwrap();
+		let ty = &field.ty;
+		let uniform = options.name.unwrap_or_else(|| ident.to_string());
+
+		calls.push(quote! {
+			callback(#uniform, <#ty as ::lykoi_drive::AsUniformType>::convert(&self.#ident));
+		});
+	}
+
+	Ok(quote! {
+		impl #impl_generics ::lykoi_drive::UniformList for #name #ty_generics #where_clause {
+			fn visit(&self, mut callback: impl FnMut(&'static str, ::lykoi_drive::UniformTypes)) {
+				#(#calls)*
+			}
+		}
+	})
+}
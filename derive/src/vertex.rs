@@ -0,0 +1,174 @@
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, LitInt, LitStr, Result};
+
+
+#[derive(Default)]
+struct FieldOptions {
+	normalized: bool,
+	integer: bool,
+	location: Option<u32>,
+	name: Option<String>,
+}
+
+fn field_options(field: &syn::Field) -> Result<FieldOptions> {
+	let mut options = FieldOptions::default();
+	for attr in &field.attrs {
+		if !attr.path().is_ident("vertex") {
+			continue;
+		}
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("normalized") {
+				options.normalized = true;
+			} else if meta.path.is_ident("integer") {
+				options.integer = true;
+			} else if meta.path.is_ident("location") {
+				let lit: LitInt = meta.value()?.parse()?;
+				options.location = Some(lit.base10_parse()?);
+			} else if meta.path.is_ident("name") {
+				let lit: LitStr = meta.value()?.parse()?;
+				options.name = Some(lit.value());
+			} else {
+				return Err(meta.error("expected `normalized`, `integer`, `location = N` or `name = \"...\"`"));
+			}
+			Ok(())
+		})?;
+	}
+	if options.normalized && options.integer {
+		return Err(Error::new_spanned(field, "a field can't be both `normalized` and `integer`"));
+	}
+	Ok(options)
+}
+
+/// whether `ty` is obviously made of floats, ie. `f32`, `[f32; 2]` or `Vector3<f32>`.
+fn is_float(ty: &syn::Type) -> bool {
+	match ty {
+		syn::Type::Array(array) => is_float(&array.elem),
+		syn::Type::Path(path) => path.path.segments.last().is_some_and(|v| matches!(
+			v.ident.to_string().as_str(),
+			"f32" | "f64" | "Vector2" | "Vector3" | "Vector4" | "Point2" | "Point3",
+		)),
+		_ => false,
+	}
+}
+
+fn struct_divisor(input: &DeriveInput) -> Result<Option<u32>> {
+	let mut divisor = None;
+	for attr in &input.attrs {
+		if !attr.path().is_ident("vertex") {
+			continue;
+		}
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("divisor") {
+				let lit: LitInt = meta.value()?.parse()?;
+				divisor = Some(lit.base10_parse()?);
+				Ok(())
+			} else {
+				Err(meta.error("expected `divisor = N`"))
+			}
+		})?;
+	}
+	Ok(divisor)
+}
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+	let Data::Struct(data) = &input.data else {
+		return Err(Error::new_spanned(&input.ident, "`VertexLayout` can only be derived for structs"));
+	};
+	let Fields::Named(fields) = &data.fields else {
+		return Err(Error::new_spanned(&input.ident, "`VertexLayout` needs named fields"));
+	};
+
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+	let mut chain = Vec::new();
+	let mut types = Vec::new();
+	let mut checks = Vec::new();
+
+	if let Some(divisor) = struct_divisor(&input)? {
+		chain.push(quote! { .divisor(#divisor) });
+	}
+
+	for field in &fields.named {
+		let options = field_options(field)?;
+		let ident = field.ident.as_ref().unwrap();
+		let ty = &field.ty;
+		types.push(ty);
+
+		if let Some(location) = options.location {
+			chain.push(quote! { .location(#location) });
+		}
+
+		if (options.normalized || options.integer) && is_float(ty) {
+			let option = if options.normalized { "normalized" } else { "integer" };
+			return Err(Error::new_spanned(ty, format!("`{}` needs an integer field", option)));
+		}
+		// types the check above can't see through, ie. aliases, are caught once `format()` is
+		// compiled.
+		if options.normalized || options.integer {
+			checks.push(quote! {
+				const {
+					assert!(
+						<#ty as ::lykoi_drive::AsVertexField>::FIELD.0.is_integer(),
+						"`normalized` and `integer` need an integer field",
+					);
+				}
+			});
+		}
+
+		let mode = if options.normalized {
+			quote! { ::lykoi_drive::VertexFieldMode::Normalized }
+		} else if options.integer {
+			quote! { ::lykoi_drive::VertexFieldMode::Integer }
+		} else {
+			quote! { ::lykoi_drive::VertexFieldMode::Float }
+		};
+
+		chain.push(quote! {
+			.offset(::core::mem::offset_of!(Self, #ident) as u32)
+			.field_typed(
+				<#ty as ::lykoi_drive::AsVertexField>::FIELD.0,
+				<#ty as ::lykoi_drive::AsVertexField>::FIELD.1,
+				#mode,
+			)
+		});
+
+		if let Some(name) = options.name {
+			chain.push(quote! { .named(#name) });
+		}
+	}
+
+	// generic structs can't be checked for padding up front, so those have to implement
+	// `Pod` by hand.
+	let pod = if input.generics.params.is_empty() {
+		quote! {
+			const _: () = {
+				fn assert_pod<T: ::lykoi_drive::Pod>() {}
+				fn assert_fields() {
+					#(assert_pod::<#types>();)*
+				}
+				assert!(
+					::core::mem::size_of::<#name>() == 0 #(+ ::core::mem::size_of::<#types>())*,
+					"vertex struct has padding between or after its fields",
+				);
+			};
+			unsafe impl ::lykoi_drive::Pod for #name {}
+		}
+	} else {
+		quote! {}
+	};
+
+	Ok(quote! {
+		impl #impl_generics ::lykoi_drive::VertexLayout for #name #ty_generics #where_clause {
+			fn format() -> ::lykoi_drive::VertexFormat {
+				#(#checks)*
+				::lykoi_drive::VertexFormat::new()
+					#(#chain)*
+					.stride(::core::mem::size_of::<Self>() as u32)
+			}
+		}
+		#pod
+	})
+}
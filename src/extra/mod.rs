@@ -1,5 +1,5 @@
 
-use crate::{Config, Shader, Surface, Texture, UniformList, VertexBuffer, VertexFormat};
+use crate::{Config, Error, IndexBuffer, Pod, Shader, Surface, Texture, UniformList, VertexBuffer, VertexBufferUsage, VertexFormat};
 
 pub trait Vertex2D {
 	fn make2d(pos: (f32, f32), tex: (f32, f32)) -> Self;
@@ -12,6 +12,7 @@ pub struct Vertex {
 	pub pos: [f32; 2],
 	pub tex: [f32; 2],
 }
+unsafe impl Pod for Vertex {}
 impl Vertex2D for Vertex {
 	fn make2d(pos: (f32, f32), tex: (f32, f32)) -> Self {
 		Self {
@@ -40,27 +41,30 @@ if a custom format `T: Vertex2D` is required, however, you can create the
 `Sprite<T>` using [`Self::new_via()`].
 */
 pub struct Sprite<'a, T = Vertex> 
-where T: Clone + Vertex2D {
+where T: Pod + Vertex2D {
 	texture: &'a Texture,
 	buffer: VertexBuffer<T>,
+	index: IndexBuffer<u8>,
 	offset: (u32, u32),
 	size: (u32, u32),
 	verts: Vec<T>,
 }
 impl<'a> Sprite<'a> {
-	/**
-	creates a new `Sprite<Vertex>`.
-	 */
+	/// creates a new `Sprite<Vertex>`.
 	pub fn new_simple(from: &'a Texture, offset: (u32, u32), size: (u32, u32)) -> Self {
 		Self::new_via(from, offset, size)
 	}
 }
 impl<'a, T> Sprite<'a, T>
-where T: Clone + Vertex2D {
+where T: Pod + Vertex2D {
 	fn new_via(from: &'a Texture, offset: (u32, u32), size: (u32, u32)) -> Self {
 		Self {
 			texture: from,
-			buffer: VertexBuffer::new(&T::get_format()),
+			buffer: VertexBuffer::new(&T::get_format())
+				.expect("Vertex2D::get_format() should match its vertex type")
+				.usage(VertexBufferUsage::Dynamic)
+				.keep_data(false),
+			index: IndexBuffer::new_from(&[0, 1, 2, 0, 3, 1]),
 			offset,
 			size,
 			verts: Vec::new(),
@@ -91,28 +95,20 @@ where T: Clone + Vertex2D {
 				(0.0, 0.0),
 				(px0, py0),
 			),
-
-			T::make2d(
-				(0.0, 1.0),
-				(px0, py1),
-			),
 			T::make2d(
 				(1.0, 1.0),
 				(px1, py1),
 			),
-			T::make2d(
-				(1.0, 0.0),
-				(px1, py0),
-			),
 		]);
 
-		self.buffer.rebuffer(&self.verts);
+		self.buffer.rebuffer(&self.verts)
+			.expect("Vertex2D::get_format() should match its vertex type");
 	}
 }
 
 
 pub trait Techniques {
-	fn draw_sprite<T: Clone + Vertex2D>(
+	fn draw_sprite<T: Pod + Vertex2D>(
 		&mut self,
 		target: Option<&Surface>,
 		sprite: &Sprite<T>,
@@ -120,9 +116,17 @@ pub trait Techniques {
 		uniform: impl UniformList,
 		config: Config,
 	);
+	fn try_draw_sprite<T: Pod + Vertex2D>(
+		&mut self,
+		target: Option<&Surface>,
+		sprite: &Sprite<T>,
+		shader: &Shader,
+		uniform: impl UniformList,
+		config: Config,
+	) -> Result<(), Error>;
 }
 impl Techniques for crate::Draw {
-	fn draw_sprite<T: Clone + Vertex2D>(
+	fn draw_sprite<T: Pod + Vertex2D>(
 		&mut self,
 		target: Option<&crate::Surface>,
 		sprite: &Sprite<T>,
@@ -130,14 +134,32 @@ impl Techniques for crate::Draw {
 		uniform: impl UniformList,
 		config: Config,
 	) {
-		self.draw(
+		self.draw_indexed(
 			target,
 			&sprite.buffer,
+			&sprite.index,
 			shader,
 			uniform,
 			config,
 		);
 	}
+	fn try_draw_sprite<T: Pod + Vertex2D>(
+		&mut self,
+		target: Option<&crate::Surface>,
+		sprite: &Sprite<T>,
+		shader: &Shader,
+		uniform: impl UniformList,
+		config: Config,
+	) -> Result<(), Error> {
+		self.try_draw_indexed(
+			target,
+			&sprite.buffer,
+			&sprite.index,
+			shader,
+			uniform,
+			config,
+		)
+	}
 }
 
 
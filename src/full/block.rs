@@ -0,0 +1,257 @@
+
+use std::marker::PhantomData;
+
+use super::{ext, AsUniformType, Error, Owned, Pod, ReleaseQueue, UniformTypes};
+
+
+/// storage buffers need OpenGL 4.3.
+const VERSION_STORAGE: (u32, u32) = (4, 3);
+
+
+/**
+types whose memory layout matches glsl's `std140` layout, and so can be put in a [`UniformBuffer`].
+
+implemented for the scalars and vectors that don't need padding in `std140`. notably, arrays
+of scalars, `mat2` and `mat3` are padded to 16 bytes per element/column in `std140`, so
+`[f32; N]`, `Matrix2` and `Matrix3` aren't `Std140`.
+
+with the `derive` feature, this can be derived for `#[repr(C)]` structs of `Std140` fields.
+the derive fails to compile if a field isn't aligned to its `std140` alignment, if the struct
+has implicit padding, or if its size isn't a multiple of 16 bytes. padding has to be written
+out as fields marked `#[std140(padding)]`, which are exempt from the alignment check:
+
+```
+# #[cfg(feature = "derive")] {
+use lykoi_drive::Std140;
+
+#[repr(C)]
+#[derive(Clone, Copy, Std140)]
+struct Light {
+    position: [f32; 3],
+    strength: f32,
+    color: [f32; 3],
+    #[std140(padding)]
+    _pad: f32,
+}
+# }
+```
+
+# Safety
+
+`ALIGN` must be the `std140` base alignment of the type, and the type's size and field offsets
+must match its `std140` layout.
+*/
+pub unsafe trait Std140: Pod {
+	/// base alignment of the type in `std140`, in bytes.
+	const ALIGN: usize;
+}
+
+macro_rules! impl_std140 {
+	($($t:ty),*) => {
+		$(
+			unsafe impl Std140 for $t {
+				const ALIGN: usize = 4;
+			}
+			unsafe impl Std140 for [$t; 2] {
+				const ALIGN: usize = 8;
+			}
+			unsafe impl Std140 for [$t; 3] {
+				const ALIGN: usize = 16;
+			}
+			unsafe impl Std140 for [$t; 4] {
+				const ALIGN: usize = 16;
+			}
+		)*
+	};
+}
+impl_std140!(f32, i32, u32);
+
+unsafe impl Std140 for [[f32; 4]; 2] {
+	const ALIGN: usize = 16;
+}
+unsafe impl Std140 for [[f32; 4]; 3] {
+	const ALIGN: usize = 16;
+}
+unsafe impl Std140 for [[f32; 4]; 4] {
+	const ALIGN: usize = 16;
+}
+unsafe impl Std140 for cgmath::Vector2<f32> {
+	const ALIGN: usize = 8;
+}
+unsafe impl Std140 for cgmath::Vector3<f32> {
+	const ALIGN: usize = 16;
+}
+unsafe impl Std140 for cgmath::Vector4<f32> {
+	const ALIGN: usize = 16;
+}
+unsafe impl Std140 for cgmath::Point2<f32> {
+	const ALIGN: usize = 8;
+}
+unsafe impl Std140 for cgmath::Point3<f32> {
+	const ALIGN: usize = 16;
+}
+unsafe impl Std140 for cgmath::Matrix4<f32> {
+	const ALIGN: usize = 16;
+}
+
+
+/**
+a buffer holding one `T`, to back a uniform block.
+
+give it to a draw call like any other uniform, under the name of the block:
+
+```ignore
+let camera = UniformBuffer::new(&Camera { ... });
+
+draw.draw(None, &model, &shader, UniformItem::new().add("Camera", &camera), Config::new());
+```
+
+each block used by a draw call is bound to its own binding point, counting up from 0.
+*/
+pub struct UniformBuffer<T: Std140> {
+	object: Owned<lykoi_gl::BufferObject>,
+	kind: PhantomData<T>,
+}
+impl<T: Std140> UniformBuffer<T> {
+	/// panics if the buffer can't be created, see [`Self::try_new()`].
+	pub fn new(value: &T) -> Self {
+		Self::try_new(value).unwrap_or_else(|e| panic!("{}", e))
+	}
+	pub fn try_new(value: &T) -> Result<Self, Error> {
+		let object = ext::gen_buffer().ok_or(Error::Create("buffer"))?;
+
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::UniformBuffer, &object);
+		lykoi_gl::buffer_data(
+			lykoi_gl::BindBufferTarget::UniformBuffer,
+			std::slice::from_ref(value),
+			lykoi_gl::BufferDataUsage::DynamicDraw,
+		);
+		lykoi_gl::unbind_buffer(lykoi_gl::BindBufferTarget::UniformBuffer);
+
+		Ok(Self {
+			object: Owned::new(object),
+			kind: PhantomData,
+		})
+	}
+
+	/// replaces the contents of the buffer.
+	pub fn update(&mut self, value: &T) {
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::UniformBuffer, &self.object);
+		ext::buffer_sub_data(lykoi_gl::BindBufferTarget::UniformBuffer, 0, std::slice::from_ref(value));
+		lykoi_gl::unbind_buffer(lykoi_gl::BindBufferTarget::UniformBuffer);
+	}
+
+	pub fn handle(&self) -> &lykoi_gl::BufferObject {
+		&self.object
+	}
+	/// defer deleting the buffer to `queue`, see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.object.release_into(queue);
+	}
+}
+
+impl<T: Std140> AsUniformType for &UniformBuffer<T> {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Block(self.handle(), size_of::<T>())
+	}
+}
+
+
+/**
+a buffer of `T`s for a shader storage block, ie. particles simulated by a [`crate::ComputeShader`].
+
+give it to a draw call or dispatch like any other uniform, under the name of the block. the
+elements are laid out as a `std430` array, which [`Std140`] types fit as long as their size is a
+multiple of their alignment (so not `[f32; 3]`, for example).
+
+each storage block used by a draw call or dispatch is bound to its own binding point, counting
+up from 0. storage buffers need OpenGL 4.3, see [`crate::ComputeShader::is_supported()`].
+*/
+pub struct StorageBuffer<T: Std140> {
+	object: Owned<lykoi_gl::BufferObject>,
+	len: usize,
+	kind: PhantomData<T>,
+}
+impl<T: Std140> StorageBuffer<T> {
+	/// panics if the buffer can't be created, see [`Self::try_new()`].
+	pub fn new(data: &[T]) -> Self {
+		Self::try_new(data).unwrap_or_else(|e| panic!("{}", e))
+	}
+	/// fails with [`Error::Unsupported`] if the context doesn't support storage buffers.
+	pub fn try_new(data: &[T]) -> Result<Self, Error> {
+		const {
+			assert!(size_of::<T>().is_multiple_of(T::ALIGN), "std430 array elements can't have padding");
+		}
+
+		if ext::gl_version() < VERSION_STORAGE {
+			return Err(Error::Unsupported {
+				feature: "storage buffers",
+				version: VERSION_STORAGE,
+			});
+		}
+
+		let object = ext::gen_buffer().ok_or(Error::Create("buffer"))?;
+		let mut s = Self {
+			object: Owned::new(object),
+			len: 0,
+			kind: PhantomData,
+		};
+		s.rebuffer(data);
+		Ok(s)
+	}
+
+	/// replaces the contents of the buffer, resizing it to fit `data`.
+	pub fn rebuffer(&mut self, data: &[T]) {
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::CopyWriteBuffer, &self.object);
+		lykoi_gl::buffer_data(lykoi_gl::BindBufferTarget::CopyWriteBuffer, data, lykoi_gl::BufferDataUsage::DynamicCopy);
+		lykoi_gl::unbind_buffer(lykoi_gl::BindBufferTarget::CopyWriteBuffer);
+		self.len = data.len();
+	}
+
+	/// overwrites the elements starting at `offset`, without resizing the buffer.
+	pub fn update_range(&mut self, offset: usize, data: &[T]) -> Result<(), Error> {
+		if offset + data.len() > self.len {
+			return Err(Error::RangeOutOfBounds {
+				start: offset,
+				end: offset + data.len(),
+				len: self.len,
+			});
+		}
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::CopyWriteBuffer, &self.object);
+		ext::buffer_sub_data(lykoi_gl::BindBufferTarget::CopyWriteBuffer, offset * size_of::<T>(), data);
+		lykoi_gl::unbind_buffer(lykoi_gl::BindBufferTarget::CopyWriteBuffer);
+		Ok(())
+	}
+
+	/// reads the buffer back. writes from a dispatch need a [`crate::Barrier::BUFFER_UPDATE`]
+	/// to be visible.
+	pub fn read(&self) -> Vec<T> {
+		// safety: T is Pod, so all zeroes is a valid T.
+		let mut out = vec![unsafe { std::mem::zeroed::<T>() }; self.len];
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::CopyReadBuffer, &self.object);
+		ext::get_buffer_sub_data(lykoi_gl::BindBufferTarget::CopyReadBuffer, 0, &mut out);
+		lykoi_gl::unbind_buffer(lykoi_gl::BindBufferTarget::CopyReadBuffer);
+		out
+	}
+
+	pub fn len(&self) -> usize {
+		self.len
+	}
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	pub fn handle(&self) -> &lykoi_gl::BufferObject {
+		&self.object
+	}
+	/// defer deleting the buffer to `queue`, see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.object.release_into(queue);
+	}
+}
+
+impl<T: Std140> AsUniformType for &StorageBuffer<T> {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Storage(self.handle(), self.len * size_of::<T>())
+	}
+}
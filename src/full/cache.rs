@@ -0,0 +1,174 @@
+
+use std::path::{Path, PathBuf};
+
+use super::{ext, Error, Preprocessed, Shader, ShaderBuilder, ShaderPartFragment, ShaderPartVertex};
+
+
+/// program binaries need OpenGL 4.1.
+const VERSION: (u32, u32) = (4, 1);
+
+
+/**
+an on-disk cache of linked programs, to skip compiling shaders on startup.
+
+programs are stored with [`glGetProgramBinary()`](https://docs.gl/gl4/glGetProgramBinary),
+keyed by a hash of their sources and of the driver's vendor, renderer and version strings. a
+driver update or a changed source simply misses the cache. if the driver rejects a cached
+binary anyway, the shader is compiled from source as usual, and the entry is replaced.
+
+the cache is best effort: failing to read or write its directory is never an error.
+
+```ignore
+let cache = ProgramCache::new("cache/shaders")?;
+
+let shader = cache.shader(SPRITE_VERT, SPRITE_FRAG)?;
+```
+*/
+pub struct ProgramCache {
+	dir: PathBuf,
+	driver: u64,
+}
+impl ProgramCache {
+	/// whether the current context can save and restore program binaries.
+	pub fn is_supported() -> bool {
+		ext::gl_version() >= VERSION && ext::get_integer_v(gl::NUM_PROGRAM_BINARY_FORMATS) > 0
+	}
+
+	/// caches programs in `dir`, which is created when the first program is stored.
+	///
+	/// fails with [`Error::Unsupported`] if the context can't save program binaries.
+	pub fn new(dir: impl Into<PathBuf>) -> Result<Self, Error> {
+		if !Self::is_supported() {
+			return Err(Error::Unsupported {
+				feature: "program binaries",
+				version: VERSION,
+			});
+		}
+
+		let mut driver = Fnv::new();
+		for name in [gl::VENDOR, gl::RENDERER, gl::VERSION] {
+			driver.write(ext::get_string(name).as_bytes());
+		}
+
+		Ok(Self {
+			dir: dir.into(),
+			driver: driver.finish(),
+		})
+	}
+
+	pub fn dir(&self) -> &Path {
+		&self.dir
+	}
+
+	/// like [`Shader::new_src()`], restoring the program from the cache if possible.
+	pub fn shader(&self, vertex_src: &str, fragment_src: &str) -> Result<Shader, Error> {
+		self.load_or_build(vertex_src, fragment_src, || Ok(ShaderBuilder::new(
+			ShaderPartVertex::new(vertex_src)?,
+			ShaderPartFragment::new(fragment_src)?,
+		)))
+	}
+
+	/// like [`Self::shader()`], for sources from a [`crate::Preprocessor`].
+	pub fn shader_preprocessed(&self, vertex: &Preprocessed, fragment: &Preprocessed) -> Result<Shader, Error> {
+		self.load_or_build(&vertex.src, &fragment.src, || Ok(ShaderBuilder::new(
+			ShaderPartVertex::new_preprocessed(vertex)?,
+			ShaderPartFragment::new_preprocessed(fragment)?,
+		)))
+	}
+
+	/// deletes every cached program.
+	pub fn clear(&self) -> std::io::Result<()> {
+		match std::fs::remove_dir_all(&self.dir) {
+			Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+			v => v,
+		}
+	}
+
+	fn load_or_build(
+		&self,
+		vertex_src: &str,
+		fragment_src: &str,
+		builder: impl FnOnce() -> Result<ShaderBuilder, Error>,
+	) -> Result<Shader, Error> {
+		let path = self.path(vertex_src, fragment_src);
+
+		if let Some(shader) = Self::load(&path) {
+			return Ok(shader);
+		}
+
+		let object = lykoi_gl::create_program().map_err(|_| Error::Create("program"))?;
+		ext::program_parameter_i(&object, gl::PROGRAM_BINARY_RETRIEVABLE_HINT, gl::TRUE as i32);
+		let shader = builder()?.link(object)?;
+
+		self.store(&path, &shader);
+
+		Ok(shader)
+	}
+
+	fn path(&self, vertex_src: &str, fragment_src: &str) -> PathBuf {
+		let mut hash = Fnv::new();
+		hash.write(&self.driver.to_le_bytes());
+		hash.write(vertex_src.as_bytes());
+		// the separator keeps moving text from one source to the other from colliding.
+		hash.write(&[0]);
+		hash.write(fragment_src.as_bytes());
+
+		self.dir.join(format!("{:016x}.bin", hash.finish()))
+	}
+
+	fn load(path: &Path) -> Option<Shader> {
+		let data = std::fs::read(path).ok()?;
+		if data.len() < 4 {
+			return None;
+		}
+		let (format, binary) = data.split_at(4);
+		let format = u32::from_le_bytes(format.try_into().unwrap());
+
+		let object = lykoi_gl::create_program().ok()?;
+		ext::program_binary(&object, format, binary);
+
+		// the driver rejects binaries it can't use anymore, ie. after an update that kept the
+		// version string.
+		if !lykoi_gl::get_program_link_status(&object) {
+			return None;
+		}
+
+		Some(Shader::from_program(object, false))
+	}
+
+	fn store(&self, path: &Path, shader: &Shader) {
+		let (format, binary) = ext::get_program_binary(shader.handle());
+		if binary.is_empty() {
+			return;
+		}
+
+		let mut data = Vec::with_capacity(4 + binary.len());
+		data.extend_from_slice(&format.to_le_bytes());
+		data.extend_from_slice(&binary);
+
+		// write to a temporary file first, so a crash never leaves a truncated entry behind.
+		let temp = path.with_extension("tmp");
+		let _ = std::fs::create_dir_all(&self.dir)
+			.and_then(|_| std::fs::write(&temp, &data))
+			.and_then(|_| std::fs::rename(&temp, path));
+	}
+}
+
+
+/// 64 bit FNV-1a, which unlike std's hasher is guaranteed to be stable across runs and
+/// compiler versions.
+struct Fnv(u64);
+impl Fnv {
+	fn new() -> Self {
+		Self(0xcbf29ce484222325)
+	}
+	fn write(&mut self, bytes: &[u8]) {
+		for v in bytes {
+			self.0 ^= *v as u64;
+			self.0 = self.0.wrapping_mul(0x100000001b3);
+		}
+	}
+	fn finish(&self) -> u64 {
+		self.0
+	}
+}
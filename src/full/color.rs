@@ -39,6 +39,14 @@ impl Color {
 			a,
 		}
 	}
+	/// the color as `[r, g, b]`, ie. for a `vec3` uniform.
+	pub fn rgb(&self) -> [f32; 3] {
+		[self.r, self.g, self.b]
+	}
+	/// the color as `[r, g, b, a]`, ie. for a `vec4` uniform.
+	pub fn rgba(&self) -> [f32; 4] {
+		[self.r, self.g, self.b, self.a]
+	}
 	pub fn from_hex(_hex: u32) -> Self {
 		todo!()
 	}
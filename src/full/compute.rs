@@ -0,0 +1,155 @@
+
+use std::ops::BitOr;
+
+use super::{ext, AsUniformType, Error, ReleaseQueue, Shader, ShaderPart, ShaderPartCompute, Texture, UniformTypes};
+
+
+/// compute shaders, storage buffers and images need OpenGL 4.3.
+const VERSION: (u32, u32) = (4, 3);
+
+
+/**
+a program made of a single compute stage, run with [`crate::Draw::dispatch()`].
+
+compute shaders need OpenGL 4.3, check [`Self::is_supported()`] before relying on them.
+*/
+pub struct ComputeShader {
+	shader: Shader,
+}
+impl ComputeShader {
+	/// whether the current context supports compute shaders.
+	pub fn is_supported() -> bool {
+		ext::gl_version() >= VERSION
+	}
+
+	/// fails with [`Error::Unsupported`] if the context doesn't support compute shaders.
+	pub fn new(part: ShaderPart<ShaderPartCompute>) -> Result<Self, Error> {
+		Self::check_supported()?;
+
+		let object = lykoi_gl::create_program().map_err(|_| Error::Create("program"))?;
+
+		ext::attach_shader(&object, part.handle());
+		lykoi_gl::link_program(&object);
+
+		if !lykoi_gl::get_program_link_status(&object) {
+			let log = lykoi_gl::get_program_info_log(&object);
+			return Err(Error::Link(log));
+		}
+
+		Ok(Self {
+			shader: Shader::from_program(object, false),
+		})
+	}
+	pub fn new_src(src: &str) -> Result<Self, Error> {
+		Self::check_supported()?;
+		Self::new(ShaderPartCompute::new(src)?)
+	}
+
+	fn check_supported() -> Result<(), Error> {
+		if !Self::is_supported() {
+			return Err(Error::Unsupported {
+				feature: "compute shaders",
+				version: VERSION,
+			});
+		}
+		Ok(())
+	}
+
+	/// the underlying program, ie. for reflection or [`Shader::validate()`].
+	pub fn get_shader(&self) -> &Shader {
+		&self.shader
+	}
+	pub fn handle(&self) -> &lykoi_gl::ProgramObject {
+		self.shader.handle()
+	}
+	/// defer deleting the program to `queue`, see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.shader.release_into(queue);
+	}
+}
+
+
+/**
+which memory accesses have to see the writes of a dispatch, see
+[`glMemoryBarrier()`](https://docs.gl/gl4/glMemoryBarrier).
+
+combine them with `|`, ie. `Barrier::STORAGE | Barrier::VERTEX_ATTRIB` after a dispatch that
+writes particles to a storage buffer that's then drawn from.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Barrier(u32);
+impl Barrier {
+	/// no barrier, for dispatches whose results aren't read until a later barrier.
+	pub const NONE: Barrier = Barrier(0);
+	/// reading the buffer as vertex data.
+	pub const VERTEX_ATTRIB: Barrier = Barrier(gl::VERTEX_ATTRIB_ARRAY_BARRIER_BIT);
+	/// reading the buffer as indices.
+	pub const ELEMENT_ARRAY: Barrier = Barrier(gl::ELEMENT_ARRAY_BARRIER_BIT);
+	/// reading the buffer as a uniform block.
+	pub const UNIFORM: Barrier = Barrier(gl::UNIFORM_BARRIER_BIT);
+	/// sampling the texture in a shader.
+	pub const TEXTURE_FETCH: Barrier = Barrier(gl::TEXTURE_FETCH_BARRIER_BIT);
+	/// accessing the texture as an image.
+	pub const IMAGE: Barrier = Barrier(gl::SHADER_IMAGE_ACCESS_BARRIER_BIT);
+	/// accessing the buffer as a storage buffer.
+	pub const STORAGE: Barrier = Barrier(gl::SHADER_STORAGE_BARRIER_BIT);
+	/// reading or writing the buffer from the cpu, ie. [`crate::StorageBuffer::read()`].
+	pub const BUFFER_UPDATE: Barrier = Barrier(gl::BUFFER_UPDATE_BARRIER_BIT);
+	/// rendering to the texture through a [`crate::Surface`].
+	pub const FRAMEBUFFER: Barrier = Barrier(gl::FRAMEBUFFER_BARRIER_BIT);
+	pub const ALL: Barrier = Barrier(gl::ALL_BARRIER_BITS);
+
+	pub fn is_empty(&self) -> bool {
+		self.0 == 0
+	}
+	pub(crate) fn bits(&self) -> u32 {
+		self.0
+	}
+}
+impl BitOr for Barrier {
+	type Output = Barrier;
+
+	fn bitor(self, rhs: Self) -> Self::Output {
+		Barrier(self.0 | rhs.0)
+	}
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageAccess {
+	Read,
+	Write,
+	ReadWrite,
+}
+impl ImageAccess {
+	pub(crate) fn to(&self) -> u32 {
+		match self {
+			ImageAccess::Read => gl::READ_ONLY,
+			ImageAccess::Write => gl::WRITE_ONLY,
+			ImageAccess::ReadWrite => gl::READ_WRITE,
+		}
+	}
+}
+
+/// the format to bind a texture with `format` to an image unit with, or `None` if image units
+/// can't use it, ie. unsized and compressed formats.
+pub(crate) fn image_format(format: lykoi_gl::TexImageInnerFormat) -> Option<u32> {
+	use lykoi_gl::TexImageInnerFormat as F;
+	match format {
+		F::RGBA32F | F::RGBA16F | F::RG32F | F::RG16F | F::R11FG11FB10F
+		| F::RGBA32UI | F::RGBA16UI | F::RGB10A2UI | F::RGBA8UI | F::RG32UI | F::RG16UI | F::RG8UI
+		| F::RGBA32I | F::RGBA16I | F::RGBA8I | F::RG32I | F::RG16I | F::RG8I
+		| F::RGBA16 | F::RGB10A2 | F::RGBA8 | F::RG16 | F::RG8
+		| F::RGBA16SNorm | F::RGBA8SNorm | F::RG16SNorm | F::RG8SNorm | F::R16SNorm | F::R8SNorm
+		=> Some(format as u32),
+		_ => None,
+	}
+}
+
+/// binds the texture to an `image2D` uniform, declared with the texture's format in the shader,
+/// `layout(rgba8)` for textures made by [`Texture::new()`].
+impl AsUniformType for (&Texture, ImageAccess) {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Image(self.0, self.1)
+	}
+}
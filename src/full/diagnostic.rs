@@ -0,0 +1,240 @@
+
+use std::fmt::Write;
+
+use super::{Error, ShaderSource, ShaderStage};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+	Error,
+	Warning,
+	/// anything else the driver said, ie. lines that don't look like a diagnostic.
+	Note,
+}
+
+/// one message from a shader compile or link log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+	/// the stage that failed to compile, `None` for link errors.
+	pub stage: Option<ShaderStage>,
+	/// source string number, see [`crate::Preprocessed::files`].
+	pub file: Option<usize>,
+	pub line: Option<usize>,
+	pub column: Option<usize>,
+	pub severity: Severity,
+	pub message: String,
+}
+impl Diagnostic {
+	/// parses a driver info log, in the formats of Mesa, NVIDIA and AMD.
+	///
+	/// ```
+	/// use lykoi_drive::{Diagnostic, Severity};
+	///
+	/// // mesa
+	/// let log = "0:12(5): error: `foo' undeclared\n0:3(1): warning: extension not supported\n";
+	/// let list = Diagnostic::parse(log);
+	/// assert_eq!(list.len(), 2);
+	/// assert_eq!((list[0].file, list[0].line, list[0].column), (Some(0), Some(12), Some(5)));
+	/// assert_eq!(list[0].severity, Severity::Error);
+	/// assert_eq!(list[0].message, "`foo' undeclared");
+	/// assert_eq!(list[1].severity, Severity::Warning);
+	///
+	/// // nvidia
+	/// let log = "0(7) : error C1008: undefined variable \"foo\"\n";
+	/// let list = Diagnostic::parse(log);
+	/// assert_eq!((list[0].file, list[0].line, list[0].column), (Some(0), Some(7), None));
+	/// assert_eq!(list[0].message, "C1008: undefined variable \"foo\"");
+	///
+	/// // amd
+	/// let log = "ERROR: 1:4: 'foo' : undeclared identifier \nERROR: 1 compilation errors.  No code generated.\n\n";
+	/// let list = Diagnostic::parse(log);
+	/// assert_eq!(list.len(), 1);
+	/// assert_eq!((list[0].file, list[0].line), (Some(1), Some(4)));
+	/// assert_eq!(list[0].message, "'foo' : undeclared identifier");
+	///
+	/// // link errors have no location
+	/// let list = Diagnostic::parse("error: vertex shader output `v_uv' not read by fragment shader\n");
+	/// assert_eq!((list[0].file, list[0].severity), (None, Severity::Error));
+	/// ```
+	pub fn parse(log: &str) -> Vec<Diagnostic> {
+		log.lines().filter_map(parse_line).collect()
+	}
+
+	/// renders the diagnostic with the offending line of `src`, which should be the file
+	/// called `name`.
+	///
+	/// ```
+	/// use lykoi_drive::Diagnostic;
+	///
+	/// let src = "#version 330 core\nvoid main() {\n    foo = 1.0;\n}\n";
+	/// let list = Diagnostic::parse("0:3(5): error: `foo' undeclared");
+	///
+	/// assert_eq!(list[0].pretty("main.frag", src), "\
+	/// error: `foo' undeclared
+	///  --> main.frag:3:5
+	///   |
+	/// 3 |     foo = 1.0;
+	///   |     ^
+	/// ");
+	/// ```
+	pub fn pretty(&self, name: &str, src: &str) -> String {
+		let mut out = String::new();
+
+		let severity = match self.severity {
+			Severity::Error => "error",
+			Severity::Warning => "warning",
+			Severity::Note => "note",
+		};
+		match self.stage {
+			Some(stage) => writeln!(out, "{} ({:?} shader): {}", severity, stage, self.message),
+			None => writeln!(out, "{}: {}", severity, self.message),
+		}.unwrap();
+
+		let Some(line) = self.line else {
+			return out;
+		};
+		match self.column {
+			Some(column) => writeln!(out, " --> {}:{}:{}", name, line, column),
+			None => writeln!(out, " --> {}:{}", name, line),
+		}.unwrap();
+
+		let Some(text) = src.lines().nth(line.saturating_sub(1)) else {
+			return out;
+		};
+		let gutter = " ".repeat(line.to_string().len());
+		writeln!(out, "{} |", gutter).unwrap();
+		writeln!(out, "{} | {}", line, text).unwrap();
+		if let Some(column) = self.column {
+			writeln!(out, "{} | {}^", gutter, " ".repeat(column.saturating_sub(1))).unwrap();
+		}
+
+		out
+	}
+
+	/// like [`Self::pretty()`], loading the file the diagnostic points at from `source`.
+	/// `files` are the files the shader was preprocessed from, see [`crate::Preprocessed`].
+	pub fn pretty_from(&self, files: &[String], source: &impl ShaderSource) -> String {
+		let name = self.file.and_then(|v| files.get(v));
+		match name.and_then(|v| Some((v, source.load(v)?))) {
+			Some((name, src)) => self.pretty(name, &src),
+			None => self.pretty("<unknown>", ""),
+		}
+	}
+}
+
+impl Error {
+	/// the parsed log of a [`Error::Compile`] or [`Error::Link`], empty for any other error.
+	pub fn diagnostics(&self) -> Vec<Diagnostic> {
+		match self {
+			Error::Compile { stage, log, .. } => {
+				let mut list = Diagnostic::parse(log);
+				for v in &mut list {
+					v.stage = Some(*stage);
+				}
+				list
+			},
+			Error::Link(log) => Diagnostic::parse(log),
+			_ => Vec::new(),
+		}
+	}
+}
+
+
+fn parse_line(line: &str) -> Option<Diagnostic> {
+	let line = line.trim();
+	if line.is_empty() || line.chars().all(|v| v == '-') {
+		return None;
+	}
+
+	let mut diagnostic = Diagnostic {
+		stage: None,
+		file: None,
+		line: None,
+		column: None,
+		severity: Severity::Note,
+		message: line.to_string(),
+	};
+
+	// amd puts the severity up front, ie. "ERROR: 0:4: message"
+	let (severity, rest) = if let Some(rest) = line.strip_prefix("ERROR: ") {
+		(Some(Severity::Error), rest)
+	} else if let Some(rest) = line.strip_prefix("WARNING: ") {
+		(Some(Severity::Warning), rest)
+	} else {
+		(None, line)
+	};
+
+	let rest = match parse_location(rest) {
+		Some((file, line, column, rest)) => {
+			diagnostic.file = Some(file);
+			diagnostic.line = Some(line);
+			diagnostic.column = column;
+			rest
+		},
+		None => rest,
+	};
+
+	match severity {
+		Some(severity) => {
+			// amd's summary line.
+			if diagnostic.line.is_none() && rest.ends_with("No code generated.") {
+				return None;
+			}
+			diagnostic.severity = severity;
+			diagnostic.message = rest.trim().to_string();
+		},
+		None => match parse_severity(rest) {
+			Some((severity, rest)) => {
+				diagnostic.severity = severity;
+				diagnostic.message = rest.trim().to_string();
+			},
+			None if diagnostic.line.is_some() => {
+				diagnostic.message = rest.trim().to_string();
+			},
+			None => (),
+		},
+	}
+
+	Some(diagnostic)
+}
+
+/// parses "file:line(column):", "file:line:" and "file(line) :" locations.
+fn parse_location(s: &str) -> Option<(usize, usize, Option<usize>, &str)> {
+	let (file, s) = parse_number(s)?;
+	if let Some(s) = s.strip_prefix(':') {
+		let (line, s) = parse_number(s)?;
+		let (column, s) = match s.strip_prefix('(') {
+			Some(s) => {
+				let (column, s) = parse_number(s)?;
+				(Some(column), s.strip_prefix(')')?)
+			},
+			None => (None, s),
+		};
+		Some((file, line, column, s.strip_prefix(':')?))
+	} else {
+		let s = s.strip_prefix('(')?;
+		let (line, s) = parse_number(s)?;
+		let s = s.strip_prefix(')')?.trim_start().strip_prefix(':')?;
+		Some((file, line, None, s))
+	}
+}
+
+fn parse_number(s: &str) -> Option<(usize, &str)> {
+	let end = s.find(|v: char| !v.is_ascii_digit()).unwrap_or(s.len());
+	let number = s[..end].parse().ok()?;
+	Some((number, &s[end..]))
+}
+
+/// parses "error: message", and nvidia's "error C1008: message".
+fn parse_severity(s: &str) -> Option<(Severity, &str)> {
+	let s = s.trim_start();
+	let end = s.find([' ', ':'])?;
+	let severity = match s[..end].to_ascii_lowercase().as_str() {
+		"error" => Severity::Error,
+		"warning" => Severity::Warning,
+		"note" | "info" => Severity::Note,
+		_ => return None,
+	};
+	let rest = &s[end..];
+	Some((severity, rest.strip_prefix(':').unwrap_or(rest)))
+}
@@ -43,11 +43,51 @@ impl ConfigBlend {
 	}
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConfigPrimitive {
+	Points,
+	Lines,
+	LineStrip,
+	LineLoop,
+	Triangles,
+	TriangleStrip,
+	TriangleFan,
+	/// patches of the given amount of vertices, for shaders with tessellation stages.
+	Patches(u8),
+}
+impl ConfigPrimitive {
+	fn to(&self) -> u32 {
+		match self {
+			ConfigPrimitive::Points => gl::POINTS,
+			ConfigPrimitive::Lines => gl::LINES,
+			ConfigPrimitive::LineStrip => gl::LINE_STRIP,
+			ConfigPrimitive::LineLoop => gl::LINE_LOOP,
+			ConfigPrimitive::Triangles => gl::TRIANGLES,
+			ConfigPrimitive::TriangleStrip => gl::TRIANGLE_STRIP,
+			ConfigPrimitive::TriangleFan => gl::TRIANGLE_FAN,
+			ConfigPrimitive::Patches(_) => gl::PATCHES,
+		}
+	}
+
+	/// whether `count` vertices make up a whole number of primitives.
+	pub fn fits(&self, count: usize) -> bool {
+		match self {
+			ConfigPrimitive::Points => true,
+			ConfigPrimitive::Lines => count.is_multiple_of(2),
+			ConfigPrimitive::LineStrip | ConfigPrimitive::LineLoop => count != 1,
+			ConfigPrimitive::Triangles => count.is_multiple_of(3),
+			ConfigPrimitive::TriangleStrip | ConfigPrimitive::TriangleFan => count == 0 || count >= 3,
+			ConfigPrimitive::Patches(size) => *size > 0 && count.is_multiple_of(*size as usize),
+		}
+	}
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
 	test_depth: bool,
 	blendmode: Option<([ConfigBlend; 2], Option<[ConfigBlend; 2]>)>,
 	blendcolor: color::Color,
+	primitive: ConfigPrimitive,
 }
 impl Config {
 	pub fn new() -> Self {
@@ -55,17 +95,18 @@ impl Config {
 			test_depth: false,
 			blendmode: None,
 			blendcolor: color::Color::from_frgba(1.0, 1.0, 1.0, 1.0),
+			primitive: ConfigPrimitive::Triangles,
 		}
 	}
 
 	pub fn depth(mut self, test: bool) -> Self {
 		self.test_depth = test;
-		return self;
+		self
 	}
 
 	pub fn blend(mut self, src: ConfigBlend, dst: ConfigBlend) -> Self {
 		self.blendmode = Some(([src, dst], None));
-		return self;
+		self
 	}
 	pub fn blend_sep(
 		mut self,
@@ -75,128 +116,388 @@ impl Config {
 		dst_a: ConfigBlend
 	) -> Self {
 		self.blendmode = Some(([src_c, dst_c], Some([src_a, dst_a])));
-		return self;
+		self
 	}
 
 	pub fn blend_color(mut self, color: color::Color) -> Self {
 		self.blendcolor = color;
-		return self;
+		self
+	}
+
+	pub fn primitive(mut self, primitive: ConfigPrimitive) -> Self {
+		self.primitive = primitive;
+		self
+	}
+}
+impl Default for Config {
+	fn default() -> Self {
+		Self::new()
 	}
 }
 
 
 pub struct Draw {
+	size: (usize, usize),
+	scale: f32,
+	release: ReleaseQueue,
 }
 impl Draw {
-	pub fn new() -> Self {
+	/// creates a new `Draw` for a default framebuffer with a logical size of `width` x `height`.
+	///
+	/// the scale factor starts at `1.0`, see [`Self::rescale()`].
+	pub fn new(width: usize, height: usize) -> Self {
 		Self {
+			size: (width, height),
+			scale: 1.0,
+			release: ReleaseQueue::new(),
 		}
 	}
 
-	pub fn clear(&mut self, target: Option<&Surface>, color: color::Color) {
+	/// updates the logical size of the default framebuffer, ie. after a window resize.
+	pub fn resize(&mut self, width: usize, height: usize) {
+		self.size = (width, height);
+	}
+
+	/// updates the ratio between framebuffer pixels and logical units of the default framebuffer.
+	///
+	/// on most HiDPI displays, this is the framebuffer size divided by the window size.
+	pub fn rescale(&mut self, scale: f32) {
+		self.scale = scale;
+	}
+
+	pub fn width(&self) -> usize {
+		self.size.0
+	}
+	pub fn height(&self) -> usize {
+		self.size.1
+	}
+	pub fn scale(&self) -> f32 {
+		self.scale
+	}
+
+	/// size of the default framebuffer in pixels, ie. the logical size multiplied by the scale factor.
+	pub fn backbuffer_size(&self) -> (usize, usize) {
+		(
+			(self.size.0 as f32 * self.scale).round() as usize,
+			(self.size.1 as f32 * self.scale).round() as usize,
+		)
+	}
+
+	/// queue for resources that should be deleted by [`Self::collect()`] instead of when dropped.
+	///
+	/// clone it and hand it to `release_into()` on resources, see [`ReleaseQueue`].
+	pub fn release_queue(&self) -> &ReleaseQueue {
+		&self.release
+	}
+
+	/// deletes every resource queued on [`Self::release_queue()`].
+	///
+	/// the context this `Draw` renders with must be current, ie. call this once a frame.
+	pub fn collect(&mut self) {
+		self.release.collect();
+	}
+
+	fn bind_target(&self, target: Option<&Surface>) {
 		if let Some(surf) = target {
 			lykoi_gl::viewport(0, 0, surf.width(), surf.height());
 			lykoi_gl::bind_framebuffer(lykoi_gl::FramebufferTarget::Framebuffer, surf.handle_framebuffer());
 		} else {
-			lykoi_gl::viewport(0, 0, 640, 480);
-			lykoi_gl::unbind_framebuffer(lykoi_gl::FramebufferTarget::Framebuffer);
+			self.bind_backbuffer();
 		}
+	}
+
+	fn bind_backbuffer(&self) {
+		let (width, height) = self.backbuffer_size();
+		lykoi_gl::viewport(0, 0, width, height);
+		lykoi_gl::unbind_framebuffer(lykoi_gl::FramebufferTarget::Framebuffer);
+	}
+
+	pub fn clear(&mut self, target: Option<&Surface>, color: color::Color) {
+		self.bind_target(target);
 
 		lykoi_gl::clear_color(color.r, color.g, color.b, color.a);
 		lykoi_gl::clear(&[lykoi_gl::BufferBit::ColorBufferBit, lykoi_gl::BufferBit::DepthBufferBit]);
 
-		if let Some(_) = target {
-			lykoi_gl::viewport(0, 0, 640, 480);
-			lykoi_gl::unbind_framebuffer(lykoi_gl::FramebufferTarget::Framebuffer);
+		if target.is_some() {
+			self.bind_backbuffer();
 		}
 	}
 
+	/// draws every vertex in `vertex`.
+	///
+	/// panics on any error, see [`Self::try_draw()`].
 	pub fn draw<T>(
 		&mut self,
 		target: Option<&Surface>,
 		vertex: &VertexBuffer<T>,
-		shader: &Shader,
+		shader: &impl Program,
 		uniform: impl UniformList,
 		config: Config,
 	) {
-		
-		if let Some(surf) = target {
-			lykoi_gl::viewport(0, 0, surf.width(), surf.height());
-			lykoi_gl::bind_framebuffer(lykoi_gl::FramebufferTarget::Framebuffer, surf.handle_framebuffer());
-		} else {
-			lykoi_gl::viewport(0, 0, 640, 480);
-			lykoi_gl::unbind_framebuffer(lykoi_gl::FramebufferTarget::Framebuffer);
-		}
-		lykoi_gl::use_program(&shader.handle());
+		self.try_draw(target, vertex, shader, uniform, config)
+			.unwrap_or_else(|e| panic!("{}", e));
+	}
 
-		let mut i_tex = 0u32;
+	pub fn try_draw<T>(
+		&mut self,
+		target: Option<&Surface>,
+		vertex: &VertexBuffer<T>,
+		shader: &impl Program,
+		uniform: impl UniformList,
+		config: Config,
+	) -> Result<(), Error> {
+		let primitive = config.primitive;
+		Self::check_primitive(shader.vertex_stage(), primitive, vertex.get_verts_len())?;
+		shader.vertex_stage().check_attributes(&[vertex.get_format()])?;
 
-		uniform.visit(|name, value| {
-			let Some(kind) = shader.get_uniform(name) else {
-				panic!("uniform name '{}' doesn't exist", name);
-			};
+		self.prepare(target, shader, uniform, config)?;
 
-			let Some(object) = lykoi_gl::get_uniform_location(
-				&shader.handle(), name,
-			) else {
-				panic!("uniform name '{}' not found", name);
-			};
+		lykoi_gl::bind_vertex_array(vertex.handle_array());
+		ext::draw_arrays(primitive.to(), 0, vertex.get_verts_len() as u32);
+		lykoi_gl::unbind_vertex_array();
 
-			match (kind, value) {
-				(lykoi_gl::UniformTypes::Float, UniformTypes::Float1(x))
-					=> lykoi_gl::uniform_1f(&object, x),
+		self.finish(target);
+		Ok(())
+	}
 
-				(lykoi_gl::UniformTypes::FloatVec2, UniformTypes::Float2([x, y]))
-					=> lykoi_gl::uniform_2f(&object, x, y),
+	/// draws `vertex` using the vertices referenced by `index`, via `glDrawElements`.
+	///
+	/// panics on any error, see [`Self::try_draw_indexed()`].
+	pub fn draw_indexed<T, I: IndexType>(
+		&mut self,
+		target: Option<&Surface>,
+		vertex: &VertexBuffer<T>,
+		index: &IndexBuffer<I>,
+		shader: &impl Program,
+		uniform: impl UniformList,
+		config: Config,
+	) {
+		self.try_draw_indexed(target, vertex, index, shader, uniform, config)
+			.unwrap_or_else(|e| panic!("{}", e));
+	}
 
-				(lykoi_gl::UniformTypes::FloatVec3, UniformTypes::Float3([x, y, z]))
-					=> lykoi_gl::uniform_3f(&object, x, y, z),
+	/// fails if `index` refers to a vertex past the end of `vertex`, or if the amount of
+	/// indices doesn't fit the configured primitive.
+	pub fn try_draw_indexed<T, I: IndexType>(
+		&mut self,
+		target: Option<&Surface>,
+		vertex: &VertexBuffer<T>,
+		index: &IndexBuffer<I>,
+		shader: &impl Program,
+		uniform: impl UniformList,
+		config: Config,
+	) -> Result<(), Error> {
+		Self::check_index(vertex, index)?;
+		let primitive = config.primitive;
+		Self::check_primitive(shader.vertex_stage(), primitive, index.get_len())?;
+		shader.vertex_stage().check_attributes(&[vertex.get_format()])?;
 
-				(lykoi_gl::UniformTypes::FloatVec3, UniformTypes::Float4([x, y, z, w]))
-					=> lykoi_gl::uniform_4f(&object, x, y, z, w),
-				
-				(_, UniformTypes::Mat4x4(v))
-					=> lykoi_gl::uniform_matrix_4fv(
-						&object,
-						false,
-						unsafe {
-							&*slice_from_raw_parts(v.as_ptr() as *const f32, 16)
-						}
-					),
+		self.prepare(target, shader, uniform, config)?;
+
+		lykoi_gl::bind_vertex_array(vertex.handle_array());
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::ElementArrayBuffer, index.handle());
+		ext::draw_elements(primitive.to(), index.get_len() as u32, I::kind(), 0);
+		lykoi_gl::unbind_vertex_array();
+
+		self.finish(target);
+		Ok(())
+	}
+
+	/// draws `vertex` once per instance in `instance`, via `glDrawArraysInstanced`.
+	///
+	/// panics on any error, see [`Self::try_draw_instanced()`].
+	pub fn draw_instanced<T, U>(
+		&mut self,
+		target: Option<&Surface>,
+		vertex: &VertexBuffer<T>,
+		instance: &VertexBuffer<U>,
+		shader: &impl Program,
+		uniform: impl UniformList,
+		config: Config,
+	) {
+		self.try_draw_instanced(target, vertex, instance, shader, uniform, config)
+			.unwrap_or_else(|e| panic!("{}", e));
+	}
+
+	/// the format of `instance` needs a divisor (see [`VertexFormat::divisor()`]), and its
+	/// locations can't overlap with the format of `vertex`.
+	pub fn try_draw_instanced<T, U>(
+		&mut self,
+		target: Option<&Surface>,
+		vertex: &VertexBuffer<T>,
+		instance: &VertexBuffer<U>,
+		shader: &impl Program,
+		uniform: impl UniformList,
+		config: Config,
+	) -> Result<(), Error> {
+		let primitive = config.primitive;
+		Self::check_primitive(shader.vertex_stage(), primitive, vertex.get_verts_len())?;
+		let instances = Self::check_instance(vertex, instance)?;
+		shader.vertex_stage().check_attributes(&[vertex.get_format(), instance.get_format()])?;
+
+		self.prepare(target, shader, uniform, config)?;
+
+		lykoi_gl::bind_vertex_array(vertex.handle_array());
+		Self::bind_instance(instance);
+		ext::draw_arrays_instanced(primitive.to(), 0, vertex.get_verts_len() as u32, instances);
+		Self::unbind_instance(instance);
+		lykoi_gl::unbind_vertex_array();
 
-				(lykoi_gl::UniformTypes::Sampler2D, UniformTypes::Sampler2D(t, f))
-					=> {
-						lykoi_gl::uniform_1i(&object, i_tex as i32);
-						lykoi_gl::active_texture(lykoi_gl::raw::TEXTURE0 + i_tex);
-
-						lykoi_gl::bind_texture(lykoi_gl::BindTextureTarget::Texture2D, &t.handle());
-
-						let value_wrap = match f.wrap {
-							TextureFormatWrap::Repeat => lykoi_gl::TexParameterWrap::Repeat,
-							TextureFormatWrap::Clamp => lykoi_gl::TexParameterWrap::ClampToEdge,
-							TextureFormatWrap::Border => lykoi_gl::TexParameterWrap::ClampToBorder,
-						};
-						lykoi_gl::tex_parameter_wrap_s(lykoi_gl::TexParameterTarget::Texture2D, value_wrap);
-						lykoi_gl::tex_parameter_wrap_t(lykoi_gl::TexParameterTarget::Texture2D, value_wrap);
-
-						let value_filter_mag = match f.filter {
-							TextureFormatFilter::Linear => lykoi_gl::TexParameterMagFilter::Linear,
-							TextureFormatFilter::Nearest => lykoi_gl::TexParameterMagFilter::Nearest,
-						};
-						let value_filter_min = match f.filter {
-							TextureFormatFilter::Linear => lykoi_gl::TexParameterMinFilter::Linear,
-							TextureFormatFilter::Nearest => lykoi_gl::TexParameterMinFilter::Nearest,
-						};
-						lykoi_gl::tex_parameter_mag_filter(lykoi_gl::TexParameterTarget::Texture2D, value_filter_mag);
-						lykoi_gl::tex_parameter_min_filter(lykoi_gl::TexParameterTarget::Texture2D, value_filter_min);
-						
-						i_tex += 1;
-					},
-
-				_ => todo!(),
+		self.finish(target);
+		Ok(())
+	}
+
+	/// combination of [`Self::draw_indexed()`] and [`Self::draw_instanced()`], via `glDrawElementsInstanced`.
+	///
+	/// panics on any error, see [`Self::try_draw_indexed_instanced()`].
+	#[allow(clippy::too_many_arguments)]
+	pub fn draw_indexed_instanced<T, U, I: IndexType>(
+		&mut self,
+		target: Option<&Surface>,
+		vertex: &VertexBuffer<T>,
+		index: &IndexBuffer<I>,
+		instance: &VertexBuffer<U>,
+		shader: &impl Program,
+		uniform: impl UniformList,
+		config: Config,
+	) {
+		self.try_draw_indexed_instanced(target, vertex, index, instance, shader, uniform, config)
+			.unwrap_or_else(|e| panic!("{}", e));
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	pub fn try_draw_indexed_instanced<T, U, I: IndexType>(
+		&mut self,
+		target: Option<&Surface>,
+		vertex: &VertexBuffer<T>,
+		index: &IndexBuffer<I>,
+		instance: &VertexBuffer<U>,
+		shader: &impl Program,
+		uniform: impl UniformList,
+		config: Config,
+	) -> Result<(), Error> {
+		Self::check_index(vertex, index)?;
+		let primitive = config.primitive;
+		Self::check_primitive(shader.vertex_stage(), primitive, index.get_len())?;
+		let instances = Self::check_instance(vertex, instance)?;
+		shader.vertex_stage().check_attributes(&[vertex.get_format(), instance.get_format()])?;
+
+		self.prepare(target, shader, uniform, config)?;
+
+		lykoi_gl::bind_vertex_array(vertex.handle_array());
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::ElementArrayBuffer, index.handle());
+		Self::bind_instance(instance);
+		ext::draw_elements_instanced(primitive.to(), index.get_len() as u32, I::kind(), 0, instances);
+		Self::unbind_instance(instance);
+		lykoi_gl::unbind_vertex_array();
+
+		self.finish(target);
+		Ok(())
+	}
+
+	/// runs `shader` over `groups` work groups, then waits on `barrier` so later accesses
+	/// see its writes.
+	///
+	/// panics on any error, see [`Self::try_dispatch()`].
+	pub fn dispatch(
+		&mut self,
+		shader: &ComputeShader,
+		uniform: impl UniformList,
+		groups: [u32; 3],
+		barrier: Barrier,
+	) {
+		self.try_dispatch(shader, uniform, groups, barrier)
+			.unwrap_or_else(|e| panic!("{}", e));
+	}
+
+	pub fn try_dispatch(
+		&mut self,
+		shader: &ComputeShader,
+		uniform: impl UniformList,
+		groups: [u32; 3],
+		barrier: Barrier,
+	) -> Result<(), Error> {
+		lykoi_gl::use_program(shader.handle());
+		Self::apply_uniforms(shader.get_shader(), uniform)?;
+
+		ext::dispatch_compute(groups);
+		self.barrier(barrier);
+
+		Ok(())
+	}
+
+	/// waits until the writes of previous dispatches are visible to `barrier`, see [`Barrier`].
+	pub fn barrier(&mut self, barrier: Barrier) {
+		if !barrier.is_empty() {
+			ext::memory_barrier(barrier.bits());
+		}
+	}
+
+	fn check_primitive(shader: &Shader, primitive: ConfigPrimitive, count: usize) -> Result<(), Error> {
+		if shader.has_tessellation() != matches!(primitive, ConfigPrimitive::Patches(_)) {
+			return Err(Error::PatchPrimitive);
+		}
+		if !primitive.fits(count) {
+			return Err(Error::PrimitiveCount { count, primitive });
+		}
+		Ok(())
+	}
+
+	fn check_index<T, I: IndexType>(vertex: &VertexBuffer<T>, index: &IndexBuffer<I>) -> Result<(), Error> {
+		if let Some(max) = index.get_max() && max as usize >= vertex.get_verts_len() {
+			return Err(Error::IndexOutOfBounds {
+				index: max as usize,
+				len: vertex.get_verts_len(),
+			});
+		}
+		Ok(())
+	}
+
+	fn check_instance<T, U>(vertex: &VertexBuffer<T>, instance: &VertexBuffer<U>) -> Result<u32, Error> {
+		let format = instance.get_format();
+		if format.get_divisor() == 0 {
+			return Err(Error::InstanceDivisor);
+		}
+		for location in format.locations() {
+			if vertex.get_format().locations().any(|v| v == location) {
+				return Err(Error::LocationOverlap(location));
 			}
+		}
+		Ok(instance.get_verts_len() as u32 * format.get_divisor())
+	}
 
-		});
+	// the instance attributes are pointed at from within the vertex's vao for the
+	// duration of the draw, then disabled again so the vao is left as it was.
+	fn bind_instance<U>(instance: &VertexBuffer<U>) {
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer, instance.handle_buffer());
+		instance.get_format().emit();
+	}
+
+	fn unbind_instance<U>(instance: &VertexBuffer<U>) {
+		for location in instance.get_format().locations() {
+			ext::disable_vertex_attrib_array(location);
+			ext::vertex_attrib_divisor(location, 0);
+		}
+	}
+
+	fn prepare(
+		&mut self,
+		target: Option<&Surface>,
+		shader: &impl Program,
+		uniform: impl UniformList,
+		config: Config,
+	) -> Result<(), Error> {
+		self.bind_target(target);
+		shader.bind();
+
+		if let ConfigPrimitive::Patches(size) = config.primitive {
+			ext::patch_parameter_vertices(size as u32);
+		}
+
+		if let Err(error) = Self::apply_uniforms(shader, uniform) {
+			self.finish(target);
+			return Err(error);
+		}
 
 		{
 			if config.test_depth {
@@ -227,15 +528,205 @@ impl Draw {
 			);
 		}
 
-		lykoi_gl::bind_vertex_array(vertex.handle_array());
-		lykoi_gl::draw_arrays(lykoi_gl::DrawPrimitives::Triangles, 0, vertex.get_verts_len() as u32);
-		lykoi_gl::unbind_vertex_array();
+		Ok(())
+	}
 
-		if let Some(_) = target {
-			lykoi_gl::viewport(0, 0, 640, 480);
-			lykoi_gl::unbind_framebuffer(lykoi_gl::FramebufferTarget::Framebuffer);
+	// sets every uniform, and binds every texture, image and buffer to its own unit or
+	// binding point, counting up from 0. with several stages, every stage that uses a name gets
+	// the same value, unit or binding point.
+	fn apply_uniforms(program: &impl Program, uniform: impl UniformList) -> Result<(), Error> {
+		let mut i_tex = 0u32;
+		let mut i_block = 0u32;
+		let mut i_storage = 0u32;
+		let mut i_image = 0u32;
+		let mut error = None;
+
+		uniform.visit(|name, value| {
+			if error.is_some() {
+				return;
+			}
+			let mut found = false;
+
+			if let UniformTypes::Block(buffer, size) | UniformTypes::Storage(buffer, size) = value {
+				let storage = matches!(value, UniformTypes::Storage(..));
+				let binding = if storage { i_storage } else { i_block };
+
+				program.visit_stages(|shader| {
+					let block = if storage {
+						shader.get_storage(name)
+					} else {
+						shader.get_block(name)
+					};
+					let Some(block) = block else {
+						return;
+					};
+					found = true;
+					if size < block.size {
+						error = Some(Error::BlockSize {
+							name: name.to_string(),
+							expected: block.size,
+							got: size,
+						});
+						return;
+					}
+					if storage {
+						ext::shader_storage_block_binding(shader.handle(), block.index, binding);
+					} else {
+						ext::uniform_block_binding(shader.handle(), block.index, binding);
+					}
+				});
+
+				if !found {
+					error = Some(Error::UniformMissing(name.to_string()));
+					return;
+				}
+				if storage {
+					ext::bind_buffer_base(gl::SHADER_STORAGE_BUFFER, binding, buffer);
+					i_storage += 1;
+				} else {
+					ext::bind_buffer_base(gl::UNIFORM_BUFFER, binding, buffer);
+					i_block += 1;
+				}
+				return;
+			}
+
+			// textures and images are bound once, and every stage using them gets the same unit.
+			let unit = match value {
+				UniformTypes::Sampler2D(t, f) => {
+					lykoi_gl::active_texture(lykoi_gl::raw::TEXTURE0 + i_tex);
+
+					lykoi_gl::bind_texture(lykoi_gl::BindTextureTarget::Texture2D, t.handle());
+
+					let value_wrap = match f.wrap {
+						TextureFormatWrap::Repeat => lykoi_gl::TexParameterWrap::Repeat,
+						TextureFormatWrap::Clamp => lykoi_gl::TexParameterWrap::ClampToEdge,
+						TextureFormatWrap::Border => lykoi_gl::TexParameterWrap::ClampToBorder,
+					};
+					lykoi_gl::tex_parameter_wrap_s(lykoi_gl::TexParameterTarget::Texture2D, value_wrap);
+					lykoi_gl::tex_parameter_wrap_t(lykoi_gl::TexParameterTarget::Texture2D, value_wrap);
+
+					let value_filter_mag = match f.filter {
+						TextureFormatFilter::Linear => lykoi_gl::TexParameterMagFilter::Linear,
+						TextureFormatFilter::Nearest => lykoi_gl::TexParameterMagFilter::Nearest,
+					};
+					let value_filter_min = match f.filter {
+						TextureFormatFilter::Linear => lykoi_gl::TexParameterMinFilter::Linear,
+						TextureFormatFilter::Nearest => lykoi_gl::TexParameterMinFilter::Nearest,
+					};
+					lykoi_gl::tex_parameter_mag_filter(lykoi_gl::TexParameterTarget::Texture2D, value_filter_mag);
+					lykoi_gl::tex_parameter_min_filter(lykoi_gl::TexParameterTarget::Texture2D, value_filter_min);
+
+					i_tex += 1;
+					i_tex - 1
+				},
+
+				UniformTypes::Image(t, access) => {
+					let Some(format) = image_format(t.format()) else {
+						error = Some(Error::ImageFormat {
+							name: name.to_string(),
+							format: t.format() as u32,
+						});
+						return;
+					};
+					ext::bind_image_texture(i_image, t.handle(), access.to(), format);
+					i_image += 1;
+					i_image - 1
+				},
+				_ => 0,
+			};
+
+			program.visit_stages(|shader| {
+				if error.is_some() {
+					return;
+				}
+				let Some(uniform) = shader.get_uniform(name) else {
+					return;
+				};
+				found = true;
+
+				if !value.fits(uniform.kind, uniform.size) {
+					error = Some(Error::UniformMismatch {
+						name: name.to_string(),
+						expected: uniform.kind,
+					});
+					return;
+				}
+
+				let location = uniform.location;
+
+				match value {
+					UniformTypes::Float1(x) => ext::uniform_1fv(location, &[x]),
+					UniformTypes::Float2(v) => ext::uniform_2fv(location, &[v]),
+					UniformTypes::Float3(v) => ext::uniform_3fv(location, &[v]),
+					UniformTypes::Float4(v) => ext::uniform_4fv(location, &[v]),
+
+					UniformTypes::Int1(x) => ext::uniform_1iv(location, &[x]),
+					UniformTypes::Int2(v) => ext::uniform_2iv(location, &[v]),
+					UniformTypes::Int3(v) => ext::uniform_3iv(location, &[v]),
+					UniformTypes::Int4(v) => ext::uniform_4iv(location, &[v]),
+
+					UniformTypes::UInt1(x) => ext::uniform_1uiv(location, &[x]),
+					UniformTypes::UInt2(v) => ext::uniform_2uiv(location, &[v]),
+					UniformTypes::UInt3(v) => ext::uniform_3uiv(location, &[v]),
+					UniformTypes::UInt4(v) => ext::uniform_4uiv(location, &[v]),
+
+					// bools are set through the int setters.
+					UniformTypes::Bool1(x) => ext::uniform_1iv(location, &[x as i32]),
+					UniformTypes::Bool2(v) => ext::uniform_2iv(location, &[v.map(i32::from)]),
+					UniformTypes::Bool3(v) => ext::uniform_3iv(location, &[v.map(i32::from)]),
+					UniformTypes::Bool4(v) => ext::uniform_4iv(location, &[v.map(i32::from)]),
+
+					UniformTypes::Mat2x2(v) => ext::uniform_matrix_fv(location, 2, 2, 1, v.as_flattened()),
+					UniformTypes::Mat3x3(v) => ext::uniform_matrix_fv(location, 3, 3, 1, v.as_flattened()),
+					UniformTypes::Mat4x4(v) => ext::uniform_matrix_fv(
+						location, 4, 4, 1,
+						unsafe {
+							&*slice_from_raw_parts(v.as_ptr(), 16)
+						},
+					),
+					UniformTypes::Mat2x3(v) => ext::uniform_matrix_fv(location, 2, 3, 1, v.as_flattened()),
+					UniformTypes::Mat2x4(v) => ext::uniform_matrix_fv(location, 2, 4, 1, v.as_flattened()),
+					UniformTypes::Mat3x2(v) => ext::uniform_matrix_fv(location, 3, 2, 1, v.as_flattened()),
+					UniformTypes::Mat3x4(v) => ext::uniform_matrix_fv(location, 3, 4, 1, v.as_flattened()),
+					UniformTypes::Mat4x2(v) => ext::uniform_matrix_fv(location, 4, 2, 1, v.as_flattened()),
+					UniformTypes::Mat4x3(v) => ext::uniform_matrix_fv(location, 4, 3, 1, v.as_flattened()),
+
+					UniformTypes::FloatArray(v) => ext::uniform_1fv(location, v),
+					UniformTypes::Float2Array(v) => ext::uniform_2fv(location, v),
+					UniformTypes::Float3Array(v) => ext::uniform_3fv(location, v),
+					UniformTypes::Float4Array(v) => ext::uniform_4fv(location, v),
+					UniformTypes::IntArray(v) => ext::uniform_1iv(location, v),
+					UniformTypes::UIntArray(v) => ext::uniform_1uiv(location, v),
+					UniformTypes::Mat4x4Array(v) => ext::uniform_matrix_fv(
+						location, 4, 4, v.len(),
+						unsafe {
+							&*slice_from_raw_parts(v.as_ptr() as *const f32, v.len() * 16)
+						},
+					),
+
+					UniformTypes::Sampler2D(..) | UniformTypes::Image(..) => ext::uniform_1iv(location, &[unit as i32]),
+
+					// handled above.
+					UniformTypes::Block(..) | UniformTypes::Storage(..) => unreachable!(),
+				}
+			});
+
+			if error.is_none() && !found {
+				error = Some(Error::UniformMissing(name.to_string()));
+			}
+
+		});
+
+		match error {
+			Some(error) => Err(error),
+			None => Ok(()),
 		}
+	}
 
+	fn finish(&mut self, target: Option<&Surface>) {
+		if target.is_some() {
+			self.bind_backbuffer();
+		}
 	}
 
 }
@@ -0,0 +1,211 @@
+
+use std::fmt;
+
+use super::{ConfigPrimitive, GlslType, ShaderStage};
+
+
+#[derive(Debug)]
+pub enum Error {
+	/// a shader stage failed to compile, with the driver's info log.
+	///
+	/// `files` are the files the source was preprocessed from (see [`crate::Preprocessed`]),
+	/// indexed by the source string numbers in the log. empty for sources given directly.
+	Compile {
+		stage: ShaderStage,
+		log: String,
+		files: Vec<String>,
+	},
+	/// a program failed to link, with the driver's info log.
+	Link(String),
+	/// the driver failed to create an object, ie. a `"program"`. usually means there's no
+	/// current context.
+	Create(&'static str),
+	/// a framebuffer isn't complete, with the status `glCheckFramebufferStatus` returned.
+	FramebufferIncomplete(u32),
+	/// a texture is bigger than `GL_MAX_TEXTURE_SIZE` in either dimension.
+	TextureSize {
+		width: usize,
+		height: usize,
+		max: usize,
+	},
+	/// the size of some data didn't match the size it was supposed to be.
+	SizeMismatch {
+		expected: usize,
+		got: usize,
+	},
+	/// the stride of a [`crate::VertexFormat`] doesn't match the size of its vertex type.
+	StrideMismatch {
+		stride: usize,
+		size: usize,
+	},
+	/// an index buffer refers to a vertex past the end of the vertex buffer.
+	IndexOutOfBounds {
+		index: usize,
+		len: usize,
+	},
+	/// a range reaches past the end of a buffer.
+	RangeOutOfBounds {
+		start: usize,
+		end: usize,
+		len: usize,
+	},
+	/// the amount of vertices drawn doesn't make up a whole number of primitives.
+	PrimitiveCount {
+		count: usize,
+		primitive: ConfigPrimitive,
+	},
+	/// a shader with tessellation stages wasn't drawn with patches, or patches were drawn
+	/// without tessellation stages.
+	PatchPrimitive,
+	/// an instance buffer's format has no divisor.
+	InstanceDivisor,
+	/// an instance buffer's format uses a location already used by the vertex buffer.
+	LocationOverlap(u32),
+	/// a uniform was given that the shader doesn't have.
+	UniformMissing(String),
+	/// a uniform was given a value that doesn't fit its type in the shader.
+	UniformMismatch {
+		name: String,
+		expected: GlslType,
+	},
+	/// the shader has a vertex attribute no vertex format feeds.
+	AttributeMissing(String),
+	/// a vertex attribute is fed by a field that doesn't fit its type, ie. an `ivec2` by floats,
+	/// a `vec2` by 4 components or a `uvec2` by signed integers.
+	AttributeMismatch {
+		name: String,
+		expected: GlslType,
+	},
+	/// a named vertex format field doesn't sit at the location of its attribute.
+	AttributeLocation {
+		name: String,
+		expected: u32,
+		got: u32,
+	},
+	/// a [`crate::ShaderSource`] doesn't have a file.
+	SourceMissing(String),
+	/// a file includes itself, directly or through other files.
+	IncludeCycle(String),
+	/// an `#include` isn't followed by a quoted path.
+	IncludeSyntax {
+		file: String,
+		line: usize,
+	},
+	/// the context's OpenGL version is too old for a feature.
+	Unsupported {
+		feature: &'static str,
+		version: (u32, u32),
+	},
+	/// a uniform or storage block was given a buffer smaller than the block.
+	BlockSize {
+		name: String,
+		expected: usize,
+		got: usize,
+	},
+	/// a texture given to an image uniform has a format image units can't use.
+	ImageFormat {
+		name: String,
+		format: u32,
+	},
+	/// an input of the fragment stage of a [`crate::ProgramPipeline`] isn't written by the
+	/// vertex stage.
+	InterfaceMissing(String),
+	/// an input of the fragment stage of a [`crate::ProgramPipeline`] has a different type
+	/// than the vertex output it's matched with.
+	InterfaceMismatch {
+		name: String,
+		expected: GlslType,
+		got: GlslType,
+	},
+}
+impl fmt::Display for Error {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Error::Compile { stage, log, files } => {
+				write!(f, "{:?} shader failed to compile: {}", stage, log)?;
+				for (id, file) in files.iter().enumerate() {
+					write!(f, "\n  source {} = {}", id, file)?;
+				}
+				Ok(())
+			},
+			Error::Link(log) => write!(f, "program failed to link: {}", log),
+			Error::Create(object) => write!(f, "failed to create {}", object),
+			Error::FramebufferIncomplete(status) => write!(f, "framebuffer incomplete (status 0x{:x})", status),
+			Error::TextureSize { width, height, max } => write!(
+				f,
+				"texture is {}x{}, but the maximum size is {}",
+				width, height, max,
+			),
+			Error::SizeMismatch { expected, got } => write!(f, "expected size {}, got {}", expected, got),
+			Error::StrideMismatch { stride, size } => write!(
+				f,
+				"vertex format stride is {} bytes, but the vertex type is {} bytes",
+				stride, size,
+			),
+			Error::IndexOutOfBounds { index, len } => write!(f, "index {} out of bounds for {} vertices", index, len),
+			Error::RangeOutOfBounds { start, end, len } => write!(
+				f,
+				"range {}..{} is out of bounds for length {}",
+				start, end, len,
+			),
+			Error::PrimitiveCount { count, primitive } => write!(
+				f,
+				"{} vertices don't fit primitive {:?}",
+				count, primitive,
+			),
+			Error::PatchPrimitive => write!(f, "patches have to be drawn with, and only with, tessellation stages"),
+			Error::InstanceDivisor => write!(f, "instance format has no divisor"),
+			Error::LocationOverlap(location) => write!(
+				f,
+				"instance location {} is already used by the vertex format",
+				location,
+			),
+			Error::UniformMissing(name) => write!(f, "uniform name '{}' doesn't exist", name),
+			Error::UniformMismatch { name, expected } => write!(
+				f,
+				"uniform '{}' was given a value that doesn't fit {:?}",
+				name, expected,
+			),
+			Error::AttributeMissing(name) => write!(f, "attribute '{}' isn't fed by the vertex format", name),
+			Error::AttributeMismatch { name, expected } => write!(
+				f,
+				"attribute '{}' is fed by a field that doesn't fit {:?}",
+				name, expected,
+			),
+			Error::AttributeLocation { name, expected, got } => write!(
+				f,
+				"attribute '{}' is at location {}, but its field is at location {}",
+				name, expected, got,
+			),
+			Error::SourceMissing(path) => write!(f, "shader source '{}' doesn't exist", path),
+			Error::IncludeCycle(path) => write!(f, "'{}' includes itself", path),
+			Error::IncludeSyntax { file, line } => write!(
+				f,
+				"{}:{}: expected a quoted path after #include",
+				file, line,
+			),
+			Error::Unsupported { feature, version } => write!(
+				f,
+				"{} need OpenGL {}.{}",
+				feature, version.0, version.1,
+			),
+			Error::ImageFormat { name, format } => write!(
+				f,
+				"image '{}' was given a texture with format 0x{:x}, which image units can't use",
+				name, format,
+			),
+			Error::BlockSize { name, expected, got } => write!(
+				f,
+				"block '{}' is {} bytes, but was given a buffer of {} bytes",
+				name, expected, got,
+			),
+			Error::InterfaceMissing(name) => write!(f, "fragment input '{}' isn't written by the vertex stage", name),
+			Error::InterfaceMismatch { name, expected, got } => write!(
+				f,
+				"fragment input '{}' is {:?}, but the vertex stage writes {:?}",
+				name, expected, got,
+			),
+		}
+	}
+}
+impl std::error::Error for Error {}
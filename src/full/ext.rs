@@ -0,0 +1,618 @@
+
+//! wrappers over OpenGL functions `lykoi_gl` doesn't cover (yet).
+
+use std::ffi::{c_char, c_void};
+
+
+/// [`glDrawArrays()`](https://docs.gl/gl3/glDrawArrays)
+pub fn draw_arrays(mode: u32, first: u32, count: u32) {
+	unsafe {
+		gl::DrawArrays(mode, first as i32, count as i32);
+	}
+}
+
+/// [`glDrawElements()`](https://docs.gl/gl3/glDrawElements)
+pub fn draw_elements(mode: u32, count: u32, kind: u32, offset: usize) {
+	unsafe {
+		gl::DrawElements(mode, count as i32, kind, offset as *const c_void);
+	}
+}
+
+/// [`glDrawArraysInstanced()`](https://docs.gl/gl3/glDrawArraysInstanced)
+pub fn draw_arrays_instanced(mode: u32, first: u32, count: u32, instances: u32) {
+	unsafe {
+		gl::DrawArraysInstanced(mode, first as i32, count as i32, instances as i32);
+	}
+}
+
+/// [`glDrawElementsInstanced()`](https://docs.gl/gl3/glDrawElementsInstanced)
+pub fn draw_elements_instanced(mode: u32, count: u32, kind: u32, offset: usize, instances: u32) {
+	unsafe {
+		gl::DrawElementsInstanced(mode, count as i32, kind, offset as *const c_void, instances as i32);
+	}
+}
+
+/// [`glVertexAttribDivisor()`](https://docs.gl/gl3/glVertexAttribDivisor)
+pub fn vertex_attrib_divisor(index: u32, divisor: u32) {
+	unsafe {
+		gl::VertexAttribDivisor(index, divisor);
+	}
+}
+
+/// [`glDisableVertexAttribArray()`](https://docs.gl/gl3/glEnableVertexAttribArray)
+pub fn disable_vertex_attrib_array(index: u32) {
+	unsafe {
+		gl::DisableVertexAttribArray(index);
+	}
+}
+
+/// [`glVertexAttribIPointer()`](https://docs.gl/gl3/glVertexAttribPointer)
+pub fn vertex_attrib_i_pointer(
+	target: u32,
+	size: u8,
+	kind: lykoi_gl::VertexAttribPointerType,
+	stride: u32,
+	offset: u32,
+) {
+	assert!(matches!(size, 1..=4));
+	unsafe {
+		gl::VertexAttribIPointer(
+			target,
+			size as i32,
+			kind as u32,
+			stride as i32,
+			offset as *const c_void,
+		);
+	}
+}
+
+/// [`glBufferData(_, _, NULL, _)`](https://docs.gl/gl3/glBufferData)
+pub fn buffer_data_empty(target: lykoi_gl::BindBufferTarget, size: usize, usage: lykoi_gl::BufferDataUsage) {
+	unsafe {
+		gl::BufferData(target as u32, size as isize, std::ptr::null(), usage as u32);
+	}
+}
+
+/// [`glBufferSubData()`](https://docs.gl/gl3/glBufferSubData)
+pub fn buffer_sub_data<T>(target: lykoi_gl::BindBufferTarget, offset: usize, data: &[T]) {
+	unsafe {
+		gl::BufferSubData(
+			target as u32,
+			offset as isize,
+			size_of_val(data) as isize,
+			data.as_ptr() as *const c_void,
+		);
+	}
+}
+
+/// [`glCheckFramebufferStatus()`](https://docs.gl/gl3/glCheckFramebufferStatus)
+pub fn check_framebuffer_status(target: lykoi_gl::FramebufferTarget) -> u32 {
+	unsafe {
+		gl::CheckFramebufferStatus(target as u32)
+	}
+}
+
+/// [`glGetProgramiv()`](https://docs.gl/gl3/glGetProgram)
+pub fn get_program_iv(program: &lykoi_gl::ProgramObject, pname: u32) -> i32 {
+	let mut out = 0;
+	unsafe {
+		gl::GetProgramiv(program.handle(), pname, &mut out);
+	}
+	out
+}
+
+/// [`glGetActiveUniform()`](https://docs.gl/gl3/glGetActiveUniform)
+///
+/// returns the name, `GL_*` type and array size of the uniform.
+pub fn get_active_uniform(program: &lykoi_gl::ProgramObject, index: u32) -> (String, u32, usize) {
+	let max = get_program_iv(program, gl::ACTIVE_UNIFORM_MAX_LENGTH).max(1);
+	let mut out_name = vec![0u8; max as usize];
+	let mut out_len = 0;
+	let mut out_size = 0;
+	let mut out_type = 0;
+	unsafe {
+		gl::GetActiveUniform(
+			program.handle(),
+			index,
+			max,
+			&mut out_len,
+			&mut out_size,
+			&mut out_type,
+			out_name.as_mut_ptr() as *mut c_char,
+		);
+	}
+	out_name.truncate(out_len as usize);
+	(String::from_utf8_lossy(&out_name).into_owned(), out_type, out_size as usize)
+}
+
+/// [`glUniform1fv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_1fv(location: i32, value: &[f32]) {
+	unsafe {
+		gl::Uniform1fv(location, value.len() as i32, value.as_ptr());
+	}
+}
+
+/// [`glUniform2fv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_2fv(location: i32, value: &[[f32; 2]]) {
+	unsafe {
+		gl::Uniform2fv(location, value.len() as i32, value.as_ptr() as *const f32);
+	}
+}
+
+/// [`glUniform3fv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_3fv(location: i32, value: &[[f32; 3]]) {
+	unsafe {
+		gl::Uniform3fv(location, value.len() as i32, value.as_ptr() as *const f32);
+	}
+}
+
+/// [`glUniform4fv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_4fv(location: i32, value: &[[f32; 4]]) {
+	unsafe {
+		gl::Uniform4fv(location, value.len() as i32, value.as_ptr() as *const f32);
+	}
+}
+
+/// [`glUniform1iv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_1iv(location: i32, value: &[i32]) {
+	unsafe {
+		gl::Uniform1iv(location, value.len() as i32, value.as_ptr());
+	}
+}
+
+/// [`glUniform2iv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_2iv(location: i32, value: &[[i32; 2]]) {
+	unsafe {
+		gl::Uniform2iv(location, value.len() as i32, value.as_ptr() as *const i32);
+	}
+}
+
+/// [`glUniform3iv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_3iv(location: i32, value: &[[i32; 3]]) {
+	unsafe {
+		gl::Uniform3iv(location, value.len() as i32, value.as_ptr() as *const i32);
+	}
+}
+
+/// [`glUniform4iv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_4iv(location: i32, value: &[[i32; 4]]) {
+	unsafe {
+		gl::Uniform4iv(location, value.len() as i32, value.as_ptr() as *const i32);
+	}
+}
+
+/// [`glUniform1uiv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_1uiv(location: i32, value: &[u32]) {
+	unsafe {
+		gl::Uniform1uiv(location, value.len() as i32, value.as_ptr());
+	}
+}
+
+/// [`glUniform2uiv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_2uiv(location: i32, value: &[[u32; 2]]) {
+	unsafe {
+		gl::Uniform2uiv(location, value.len() as i32, value.as_ptr() as *const u32);
+	}
+}
+
+/// [`glUniform3uiv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_3uiv(location: i32, value: &[[u32; 3]]) {
+	unsafe {
+		gl::Uniform3uiv(location, value.len() as i32, value.as_ptr() as *const u32);
+	}
+}
+
+/// [`glUniform4uiv()`](https://docs.gl/gl3/glUniform)
+pub fn uniform_4uiv(location: i32, value: &[[u32; 4]]) {
+	unsafe {
+		gl::Uniform4uiv(location, value.len() as i32, value.as_ptr() as *const u32);
+	}
+}
+
+/// [`glUniformMatrix*fv()`](https://docs.gl/gl3/glUniform)
+///
+/// `value` holds `count` column major matrices of `columns` x `rows`.
+pub fn uniform_matrix_fv(location: i32, columns: u8, rows: u8, count: usize, value: &[f32]) {
+	assert!(value.len() == columns as usize * rows as usize * count);
+	let f = match (columns, rows) {
+		(2, 2) => gl::UniformMatrix2fv,
+		(3, 3) => gl::UniformMatrix3fv,
+		(4, 4) => gl::UniformMatrix4fv,
+		(2, 3) => gl::UniformMatrix2x3fv,
+		(2, 4) => gl::UniformMatrix2x4fv,
+		(3, 2) => gl::UniformMatrix3x2fv,
+		(3, 4) => gl::UniformMatrix3x4fv,
+		(4, 2) => gl::UniformMatrix4x2fv,
+		(4, 3) => gl::UniformMatrix4x3fv,
+		_ => unreachable!(),
+	};
+	unsafe {
+		f(location, count as i32, gl::FALSE, value.as_ptr());
+	}
+}
+
+/// [`glGetActiveUniformBlockiv()`](https://docs.gl/gl3/glGetActiveUniformBlock)
+pub fn get_active_uniform_block_iv(program: &lykoi_gl::ProgramObject, index: u32, pname: u32) -> i32 {
+	let mut out = 0;
+	unsafe {
+		gl::GetActiveUniformBlockiv(program.handle(), index, pname, &mut out);
+	}
+	out
+}
+
+/// [`glGetActiveUniformBlockName()`](https://docs.gl/gl3/glGetActiveUniformBlockName)
+pub fn get_active_uniform_block_name(program: &lykoi_gl::ProgramObject, index: u32) -> String {
+	let max = get_active_uniform_block_iv(program, index, gl::UNIFORM_BLOCK_NAME_LENGTH).max(1);
+	let mut out_name = vec![0u8; max as usize];
+	let mut out_len = 0;
+	unsafe {
+		gl::GetActiveUniformBlockName(
+			program.handle(),
+			index,
+			max,
+			&mut out_len,
+			out_name.as_mut_ptr() as *mut c_char,
+		);
+	}
+	out_name.truncate(out_len as usize);
+	String::from_utf8_lossy(&out_name).into_owned()
+}
+
+/// [`glUniformBlockBinding()`](https://docs.gl/gl3/glUniformBlockBinding)
+pub fn uniform_block_binding(program: &lykoi_gl::ProgramObject, index: u32, binding: u32) {
+	unsafe {
+		gl::UniformBlockBinding(program.handle(), index, binding);
+	}
+}
+
+/// [`glBindBufferBase()`](https://docs.gl/gl4/glBindBufferBase)
+///
+/// takes a raw target, since `lykoi_gl::BindBufferTarget` has no `GL_SHADER_STORAGE_BUFFER`.
+pub fn bind_buffer_base(target: u32, index: u32, buffer: &lykoi_gl::BufferObject) {
+	unsafe {
+		gl::BindBufferBase(target, index, buffer.handle());
+	}
+}
+
+/// [`glGetActiveAttrib()`](https://docs.gl/gl3/glGetActiveAttrib)
+///
+/// returns the name, `GL_*` type and array size of the attribute.
+pub fn get_active_attrib(program: &lykoi_gl::ProgramObject, index: u32) -> (String, u32, usize) {
+	let max = get_program_iv(program, gl::ACTIVE_ATTRIBUTE_MAX_LENGTH).max(1);
+	let mut out_name = vec![0u8; max as usize];
+	let mut out_len = 0;
+	let mut out_size = 0;
+	let mut out_type = 0;
+	unsafe {
+		gl::GetActiveAttrib(
+			program.handle(),
+			index,
+			max,
+			&mut out_len,
+			&mut out_size,
+			&mut out_type,
+			out_name.as_mut_ptr() as *mut c_char,
+		);
+	}
+	out_name.truncate(out_len as usize);
+	(String::from_utf8_lossy(&out_name).into_owned(), out_type, out_size as usize)
+}
+
+/// [`glGetAttribLocation()`](https://docs.gl/gl3/glGetAttribLocation)
+///
+/// `None` for built-in attributes like `gl_VertexID`.
+pub fn get_attrib_location(program: &lykoi_gl::ProgramObject, name: &str) -> Option<u32> {
+	let name = std::ffi::CString::new(name).unwrap();
+	let out = unsafe {
+		gl::GetAttribLocation(program.handle(), name.as_ptr())
+	};
+	if out < 0 {
+		None
+	} else {
+		Some(out as u32)
+	}
+}
+
+/// [`glPatchParameteri(GL_PATCH_VERTICES, _)`](https://docs.gl/gl4/glPatchParameter)
+pub fn patch_parameter_vertices(count: u32) {
+	unsafe {
+		gl::PatchParameteri(gl::PATCH_VERTICES, count as i32);
+	}
+}
+
+/// a shader object of any stage, including the ones `lykoi_gl::ShaderType` doesn't have.
+#[derive(Debug)]
+pub struct ShaderObject(u32);
+impl ShaderObject {
+	pub fn handle(&self) -> u32 {
+		self.0
+	}
+}
+impl Drop for ShaderObject {
+	fn drop(&mut self) {
+		unsafe {
+			gl::DeleteShader(self.0);
+		}
+	}
+}
+
+/// [`glGenBuffers()`](https://docs.gl/gl3/glGenBuffers), for one buffer. `None` if no name was
+/// generated, ie. without a current context.
+pub fn gen_buffer() -> Option<lykoi_gl::BufferObject> {
+	let [object] = lykoi_gl::gen_buffers();
+	(object.handle() != 0).then_some(object)
+}
+
+/// [`glCreateShader()`](https://docs.gl/gl4/glCreateShader)
+pub fn create_shader(kind: u32) -> Option<ShaderObject> {
+	let out = unsafe { gl::CreateShader(kind) };
+	if out == 0 {
+		None
+	} else {
+		Some(ShaderObject(out))
+	}
+}
+
+/// [`glShaderSource()`](https://docs.gl/gl3/glShaderSource)
+pub fn shader_source(shader: &ShaderObject, src: &str) {
+	let string = src.as_ptr() as *const c_char;
+	let length = src.len() as i32;
+	unsafe {
+		gl::ShaderSource(shader.handle(), 1, &string, &length);
+	}
+}
+
+/// [`glCompileShader()`](https://docs.gl/gl3/glCompileShader)
+pub fn compile_shader(shader: &ShaderObject) {
+	unsafe {
+		gl::CompileShader(shader.handle());
+	}
+}
+
+/// [`glGetShaderiv(_, GL_COMPILE_STATUS, _)`](https://docs.gl/gl3/glGetShader)
+pub fn get_shader_compile_status(shader: &ShaderObject) -> bool {
+	let mut out = 0;
+	unsafe {
+		gl::GetShaderiv(shader.handle(), gl::COMPILE_STATUS, &mut out);
+	}
+	out == gl::TRUE as i32
+}
+
+/// [`glGetShaderInfoLog()`](https://docs.gl/gl3/glGetShaderInfoLog)
+pub fn get_shader_info_log(shader: &ShaderObject) -> String {
+	let mut max = 0;
+	unsafe {
+		gl::GetShaderiv(shader.handle(), gl::INFO_LOG_LENGTH, &mut max);
+	}
+	let mut out_log = vec![0u8; max.max(1) as usize];
+	let mut out_len = 0;
+	unsafe {
+		gl::GetShaderInfoLog(shader.handle(), max, &mut out_len, out_log.as_mut_ptr() as *mut c_char);
+	}
+	out_log.truncate(out_len as usize);
+	String::from_utf8_lossy(&out_log).into_owned()
+}
+
+/// [`glAttachShader()`](https://docs.gl/gl3/glAttachShader)
+pub fn attach_shader(program: &lykoi_gl::ProgramObject, shader: &ShaderObject) {
+	unsafe {
+		gl::AttachShader(program.handle(), shader.handle());
+	}
+}
+
+/// [`glGetIntegerv()`](https://docs.gl/gl3/glGet)
+pub fn get_integer_v(pname: u32) -> i32 {
+	let mut out = 0;
+	unsafe {
+		gl::GetIntegerv(pname, &mut out);
+	}
+	out
+}
+
+/// the `(major, minor)` version of the current context.
+pub fn gl_version() -> (u32, u32) {
+	(get_integer_v(gl::MAJOR_VERSION) as u32, get_integer_v(gl::MINOR_VERSION) as u32)
+}
+
+/// [`glGetProgramInterfaceiv()`](https://docs.gl/gl4/glGetProgramInterface)
+pub fn get_program_interface_iv(program: &lykoi_gl::ProgramObject, interface: u32, pname: u32) -> i32 {
+	let mut out = 0;
+	unsafe {
+		gl::GetProgramInterfaceiv(program.handle(), interface, pname, &mut out);
+	}
+	out
+}
+
+/// [`glGetProgramResourceName()`](https://docs.gl/gl4/glGetProgramResourceName)
+pub fn get_program_resource_name(program: &lykoi_gl::ProgramObject, interface: u32, index: u32) -> String {
+	let max = get_program_interface_iv(program, interface, gl::MAX_NAME_LENGTH).max(1);
+	let mut out_name = vec![0u8; max as usize];
+	let mut out_len = 0;
+	unsafe {
+		gl::GetProgramResourceName(
+			program.handle(),
+			interface,
+			index,
+			max,
+			&mut out_len,
+			out_name.as_mut_ptr() as *mut c_char,
+		);
+	}
+	out_name.truncate(out_len as usize);
+	String::from_utf8_lossy(&out_name).into_owned()
+}
+
+/// [`glGetProgramResourceiv()`](https://docs.gl/gl4/glGetProgramResource), for a single property.
+pub fn get_program_resource_iv(program: &lykoi_gl::ProgramObject, interface: u32, index: u32, prop: u32) -> i32 {
+	let mut out = 0;
+	unsafe {
+		gl::GetProgramResourceiv(
+			program.handle(),
+			interface,
+			index,
+			1,
+			&prop,
+			1,
+			std::ptr::null_mut(),
+			&mut out,
+		);
+	}
+	out
+}
+
+/// [`glGetProgramResourceiv()`](https://docs.gl/gl4/glGetProgramResource), for the indices of
+/// a block's variables.
+pub fn get_program_resource_active_variables(program: &lykoi_gl::ProgramObject, interface: u32, index: u32) -> Vec<u32> {
+	let amount = get_program_resource_iv(program, interface, index, gl::NUM_ACTIVE_VARIABLES);
+	let mut out = vec![0i32; amount.max(0) as usize];
+	unsafe {
+		gl::GetProgramResourceiv(
+			program.handle(),
+			interface,
+			index,
+			1,
+			&gl::ACTIVE_VARIABLES,
+			out.len() as i32,
+			std::ptr::null_mut(),
+			out.as_mut_ptr(),
+		);
+	}
+	out.into_iter().map(|v| v as u32).collect()
+}
+
+/// [`glShaderStorageBlockBinding()`](https://docs.gl/gl4/glShaderStorageBlockBinding)
+pub fn shader_storage_block_binding(program: &lykoi_gl::ProgramObject, index: u32, binding: u32) {
+	unsafe {
+		gl::ShaderStorageBlockBinding(program.handle(), index, binding);
+	}
+}
+
+/// [`glBindImageTexture()`](https://docs.gl/gl4/glBindImageTexture)
+pub fn bind_image_texture(unit: u32, texture: &lykoi_gl::TextureObject, access: u32, format: u32) {
+	unsafe {
+		gl::BindImageTexture(unit, texture.handle(), 0, gl::FALSE, 0, access, format);
+	}
+}
+
+/// [`glDispatchCompute()`](https://docs.gl/gl4/glDispatchCompute)
+pub fn dispatch_compute(groups: [u32; 3]) {
+	unsafe {
+		gl::DispatchCompute(groups[0], groups[1], groups[2]);
+	}
+}
+
+/// [`glMemoryBarrier()`](https://docs.gl/gl4/glMemoryBarrier)
+pub fn memory_barrier(barriers: u32) {
+	unsafe {
+		gl::MemoryBarrier(barriers);
+	}
+}
+
+/// [`glGetBufferSubData()`](https://docs.gl/gl3/glGetBufferSubData)
+pub fn get_buffer_sub_data<T>(target: lykoi_gl::BindBufferTarget, offset: usize, out: &mut [T]) {
+	unsafe {
+		gl::GetBufferSubData(
+			target as u32,
+			offset as isize,
+			size_of_val(out) as isize,
+			out.as_mut_ptr() as *mut c_void,
+		);
+	}
+}
+
+/// [`glGetString()`](https://docs.gl/gl3/glGetString)
+pub fn get_string(name: u32) -> String {
+	unsafe {
+		let out = gl::GetString(name);
+		if out.is_null() {
+			return String::new();
+		}
+		std::ffi::CStr::from_ptr(out as *const c_char).to_string_lossy().into_owned()
+	}
+}
+
+/// [`glProgramParameteri()`](https://docs.gl/gl4/glProgramParameter)
+pub fn program_parameter_i(program: &lykoi_gl::ProgramObject, pname: u32, value: i32) {
+	unsafe {
+		gl::ProgramParameteri(program.handle(), pname, value);
+	}
+}
+
+/// [`glGetProgramBinary()`](https://docs.gl/gl4/glGetProgramBinary), as `(format, binary)`.
+pub fn get_program_binary(program: &lykoi_gl::ProgramObject) -> (u32, Vec<u8>) {
+	let len = get_program_iv(program, gl::PROGRAM_BINARY_LENGTH);
+	let mut out = vec![0u8; len as usize];
+	let mut out_len = 0;
+	let mut format = 0;
+	unsafe {
+		gl::GetProgramBinary(
+			program.handle(),
+			len,
+			&mut out_len,
+			&mut format,
+			out.as_mut_ptr() as *mut c_void,
+		);
+	}
+	out.truncate(out_len as usize);
+	(format, out)
+}
+
+/// [`glProgramBinary()`](https://docs.gl/gl4/glProgramBinary)
+pub fn program_binary(program: &lykoi_gl::ProgramObject, format: u32, binary: &[u8]) {
+	unsafe {
+		gl::ProgramBinary(
+			program.handle(),
+			format,
+			binary.as_ptr() as *const c_void,
+			binary.len() as i32,
+		);
+	}
+}
+
+/// a program pipeline object, which `lykoi_gl` doesn't have.
+#[derive(Debug)]
+pub struct PipelineObject(u32);
+impl Drop for PipelineObject {
+	fn drop(&mut self) {
+		unsafe {
+			gl::DeleteProgramPipelines(1, &self.0);
+		}
+	}
+}
+
+/// [`glGenProgramPipelines()`](https://docs.gl/gl4/glGenProgramPipelines)
+pub fn gen_program_pipeline() -> PipelineObject {
+	let mut out = 0;
+	unsafe {
+		gl::GenProgramPipelines(1, &mut out);
+	}
+	PipelineObject(out)
+}
+
+/// [`glBindProgramPipeline()`](https://docs.gl/gl4/glBindProgramPipeline)
+pub fn bind_program_pipeline(pipeline: &PipelineObject) {
+	unsafe {
+		gl::BindProgramPipeline(pipeline.0);
+	}
+}
+
+/// [`glUseProgramStages()`](https://docs.gl/gl4/glUseProgramStages)
+pub fn use_program_stages(pipeline: &PipelineObject, stages: u32, program: &lykoi_gl::ProgramObject) {
+	unsafe {
+		gl::UseProgramStages(pipeline.0, stages, program.handle());
+	}
+}
+
+/// [`glActiveShaderProgram()`](https://docs.gl/gl4/glActiveShaderProgram)
+pub fn active_shader_program(pipeline: &PipelineObject, program: &lykoi_gl::ProgramObject) {
+	unsafe {
+		gl::ActiveShaderProgram(pipeline.0, program.handle());
+	}
+}
+
+/// [`glUseProgram()`](https://docs.gl/gl3/glUseProgram) with 0, so a bound pipeline takes effect.
+pub fn unuse_program() {
+	unsafe {
+		gl::UseProgram(0);
+	}
+}
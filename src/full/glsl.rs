@@ -0,0 +1,115 @@
+
+/// type of a variable in a shader, as reported by the driver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GlslType {
+	Float,
+	Vec2,
+	Vec3,
+	Vec4,
+	Int,
+	IVec2,
+	IVec3,
+	IVec4,
+	UInt,
+	UVec2,
+	UVec3,
+	UVec4,
+	Bool,
+	BVec2,
+	BVec3,
+	BVec4,
+	Mat2,
+	Mat3,
+	Mat4,
+	Mat2x3,
+	Mat2x4,
+	Mat3x2,
+	Mat3x4,
+	Mat4x2,
+	Mat4x3,
+	Sampler1D,
+	Sampler2D,
+	Sampler3D,
+	SamplerCube,
+	Sampler2DArray,
+	Image2D,
+	/// any other type, with its `GL_*` enum.
+	Other(u32),
+}
+impl GlslType {
+	/// the columns, rows and whether it's an integer type, for types usable as vertex attributes.
+	///
+	/// each column of a matrix takes up its own attribute location.
+	pub fn attribute_shape(&self) -> Option<(u8, u8, bool)> {
+		let shape = match self {
+			GlslType::Float => (1, 1, false),
+			GlslType::Vec2 => (1, 2, false),
+			GlslType::Vec3 => (1, 3, false),
+			GlslType::Vec4 => (1, 4, false),
+			GlslType::Int | GlslType::UInt => (1, 1, true),
+			GlslType::IVec2 | GlslType::UVec2 => (1, 2, true),
+			GlslType::IVec3 | GlslType::UVec3 => (1, 3, true),
+			GlslType::IVec4 | GlslType::UVec4 => (1, 4, true),
+			GlslType::Mat2 => (2, 2, false),
+			GlslType::Mat3 => (3, 3, false),
+			GlslType::Mat4 => (4, 4, false),
+			GlslType::Mat2x3 => (2, 3, false),
+			GlslType::Mat2x4 => (2, 4, false),
+			GlslType::Mat3x2 => (3, 2, false),
+			GlslType::Mat3x4 => (3, 4, false),
+			GlslType::Mat4x2 => (4, 2, false),
+			GlslType::Mat4x3 => (4, 3, false),
+			_ => return None,
+		};
+		Some(shape)
+	}
+
+	/// whether this is `uint` or a `uvecN`.
+	pub fn is_unsigned(&self) -> bool {
+		matches!(self, GlslType::UInt | GlslType::UVec2 | GlslType::UVec3 | GlslType::UVec4)
+	}
+
+	pub(crate) fn from_gl(kind: u32) -> Self {
+		match kind {
+			gl::FLOAT => GlslType::Float,
+			gl::FLOAT_VEC2 => GlslType::Vec2,
+			gl::FLOAT_VEC3 => GlslType::Vec3,
+			gl::FLOAT_VEC4 => GlslType::Vec4,
+
+			gl::INT => GlslType::Int,
+			gl::INT_VEC2 => GlslType::IVec2,
+			gl::INT_VEC3 => GlslType::IVec3,
+			gl::INT_VEC4 => GlslType::IVec4,
+
+			gl::UNSIGNED_INT => GlslType::UInt,
+			gl::UNSIGNED_INT_VEC2 => GlslType::UVec2,
+			gl::UNSIGNED_INT_VEC3 => GlslType::UVec3,
+			gl::UNSIGNED_INT_VEC4 => GlslType::UVec4,
+
+			gl::BOOL => GlslType::Bool,
+			gl::BOOL_VEC2 => GlslType::BVec2,
+			gl::BOOL_VEC3 => GlslType::BVec3,
+			gl::BOOL_VEC4 => GlslType::BVec4,
+
+			gl::FLOAT_MAT2 => GlslType::Mat2,
+			gl::FLOAT_MAT3 => GlslType::Mat3,
+			gl::FLOAT_MAT4 => GlslType::Mat4,
+			gl::FLOAT_MAT2x3 => GlslType::Mat2x3,
+			gl::FLOAT_MAT2x4 => GlslType::Mat2x4,
+			gl::FLOAT_MAT3x2 => GlslType::Mat3x2,
+			gl::FLOAT_MAT3x4 => GlslType::Mat3x4,
+			gl::FLOAT_MAT4x2 => GlslType::Mat4x2,
+			gl::FLOAT_MAT4x3 => GlslType::Mat4x3,
+
+			gl::SAMPLER_1D => GlslType::Sampler1D,
+			gl::SAMPLER_2D => GlslType::Sampler2D,
+			gl::SAMPLER_3D => GlslType::Sampler3D,
+			gl::SAMPLER_CUBE => GlslType::SamplerCube,
+			gl::SAMPLER_2D_ARRAY => GlslType::Sampler2DArray,
+
+			gl::IMAGE_2D => GlslType::Image2D,
+
+			other => GlslType::Other(other),
+		}
+	}
+}
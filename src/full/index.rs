@@ -0,0 +1,101 @@
+
+use super::{ext, Error, Owned, ReleaseQueue};
+
+
+/// element types usable in an [`IndexBuffer`].
+pub trait IndexType: Copy {
+	/// the matching `GL_UNSIGNED_*` type.
+	fn kind() -> u32;
+	fn to_u32(self) -> u32;
+}
+impl IndexType for u8 {
+	fn kind() -> u32 {
+		gl::UNSIGNED_BYTE
+	}
+	fn to_u32(self) -> u32 {
+		self as u32
+	}
+}
+impl IndexType for u16 {
+	fn kind() -> u32 {
+		gl::UNSIGNED_SHORT
+	}
+	fn to_u32(self) -> u32 {
+		self as u32
+	}
+}
+impl IndexType for u32 {
+	fn kind() -> u32 {
+		gl::UNSIGNED_INT
+	}
+	fn to_u32(self) -> u32 {
+		self
+	}
+}
+
+
+pub struct IndexBuffer<T: IndexType> {
+	object: Owned<lykoi_gl::BufferObject>,
+	data: Option<Vec<T>>,
+	max: Option<u32>,
+}
+impl<T: IndexType> IndexBuffer<T> {
+	/// panics if the buffer can't be created, see [`Self::try_new()`].
+	pub fn new() -> Self {
+		Self::try_new().unwrap_or_else(|e| panic!("{}", e))
+	}
+	pub fn try_new() -> Result<Self, Error> {
+		let object = ext::gen_buffer().ok_or(Error::Create("buffer"))?;
+
+		Ok(Self {
+			object: Owned::new(object),
+			data: None,
+			max: None,
+		})
+	}
+
+	/// panics if the buffer can't be created, see [`Self::try_new_from()`].
+	pub fn new_from(data: &[T]) -> Self {
+		Self::try_new_from(data).unwrap_or_else(|e| panic!("{}", e))
+	}
+	pub fn try_new_from(data: &[T]) -> Result<Self, Error> {
+		let mut s = Self::try_new()?;
+		s.rebuffer(data);
+		Ok(s)
+	}
+
+	pub fn rebuffer(&mut self, data: &[T]) {
+		// the element array binding belongs to whichever vao is bound,
+		// so upload through a target that doesn't touch vao state.
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::CopyWriteBuffer, &self.object);
+		lykoi_gl::buffer_data(lykoi_gl::BindBufferTarget::CopyWriteBuffer, data, lykoi_gl::BufferDataUsage::StaticDraw);
+		lykoi_gl::unbind_buffer(lykoi_gl::BindBufferTarget::CopyWriteBuffer);
+
+		self.max = data.iter().map(|v| v.to_u32()).max();
+		self.data = Some(data.to_vec());
+	}
+
+	pub fn handle(&self) -> &lykoi_gl::BufferObject {
+		&self.object
+	}
+	/// defer deleting the buffer to `queue`, see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.object.release_into(queue);
+	}
+	pub fn get_len(&self) -> usize {
+		if let Some(ref data) = self.data {
+			data.len()
+		} else {
+			0
+		}
+	}
+	/// the largest index in the buffer, or `None` if it's empty.
+	pub fn get_max(&self) -> Option<u32> {
+		self.max
+	}
+}
+impl<T: IndexType> Default for IndexBuffer<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
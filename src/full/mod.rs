@@ -1,14 +1,40 @@
 
 pub mod color;
 mod shader;
+mod pipeline;
+mod preprocess;
+mod reload;
+mod cache;
+mod variants;
+mod diagnostic;
 mod uniform;
+mod block;
+mod compute;
 mod texture;
 mod vertex;
+mod index;
 mod draw;
+mod release;
+mod error;
+mod ext;
+mod glsl;
 
 pub use shader::*;
+pub use pipeline::*;
+pub use preprocess::*;
+pub use reload::*;
+pub use cache::*;
+pub use variants::*;
+pub use diagnostic::*;
 pub use uniform::*;
+pub use block::*;
+pub use compute::*;
 pub use texture::*;
 pub use vertex::*;
+pub use index::*;
 pub use draw::*;
+pub use glsl::*;
+pub use error::Error;
+pub use release::ReleaseQueue;
+pub(crate) use release::Owned;
 
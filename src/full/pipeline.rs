@@ -0,0 +1,250 @@
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use super::{ext, Error, GlslType, Owned, Program, ReleaseQueue, Shader, ShaderPart, ShaderPartFragment, ShaderPartVertex};
+
+
+/// separable programs need OpenGL 4.1.
+const VERSION: (u32, u32) = (4, 1);
+/// reflecting the inputs and outputs of a program needs OpenGL 4.3.
+const VERSION_INTERFACE: (u32, u32) = (4, 3);
+
+
+/// an input or output of a stage in a [`SeparableShader`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShaderVarying {
+	pub kind: GlslType,
+	/// amount of array elements, `1` if it isn't an array.
+	pub size: usize,
+	/// the location given with `layout(location = ...)`, if any.
+	pub location: Option<u32>,
+}
+
+
+/**
+a single stage linked into its own program, to be combined with other stages in a
+[`ProgramPipeline`].
+
+linking a vertex stage once and pairing it with many fragment stages saves linking a [`Shader`]
+for every combination. separable programs need OpenGL 4.1, see [`Self::is_supported()`].
+*/
+pub struct SeparableShader<T> {
+	shader: Shader,
+	inputs: HashMap<String, ShaderVarying>,
+	outputs: HashMap<String, ShaderVarying>,
+	kind: PhantomData<T>,
+}
+impl SeparableShader<ShaderPartVertex> {
+	pub fn new(part: ShaderPart<ShaderPartVertex>) -> Result<Self, Error> {
+		Self::link(part)
+	}
+	pub fn new_src(src: &str) -> Result<Self, Error> {
+		Self::check_supported()?;
+		Self::new(ShaderPartVertex::new(src)?)
+	}
+}
+impl SeparableShader<ShaderPartFragment> {
+	pub fn new(part: ShaderPart<ShaderPartFragment>) -> Result<Self, Error> {
+		Self::link(part)
+	}
+	pub fn new_src(src: &str) -> Result<Self, Error> {
+		Self::check_supported()?;
+		Self::new(ShaderPartFragment::new(src)?)
+	}
+}
+impl<T> SeparableShader<T> {
+	/// whether the current context supports separable programs.
+	pub fn is_supported() -> bool {
+		ext::gl_version() >= VERSION
+	}
+
+	fn check_supported() -> Result<(), Error> {
+		if !Self::is_supported() {
+			return Err(Error::Unsupported {
+				feature: "separable programs",
+				version: VERSION,
+			});
+		}
+		Ok(())
+	}
+
+	fn link(part: ShaderPart<T>) -> Result<Self, Error> {
+		Self::check_supported()?;
+
+		let object = lykoi_gl::create_program().map_err(|_| Error::Create("program"))?;
+
+		ext::program_parameter_i(&object, gl::PROGRAM_SEPARABLE, gl::TRUE as i32);
+		ext::attach_shader(&object, part.handle());
+		lykoi_gl::link_program(&object);
+
+		if !lykoi_gl::get_program_link_status(&object) {
+			let log = lykoi_gl::get_program_info_log(&object);
+			return Err(Error::Link(log));
+		}
+
+		let (inputs, outputs) = if ext::gl_version() >= VERSION_INTERFACE {
+			(
+				reflect_interface(&object, gl::PROGRAM_INPUT),
+				reflect_interface(&object, gl::PROGRAM_OUTPUT),
+			)
+		} else {
+			(HashMap::new(), HashMap::new())
+		};
+
+		Ok(Self {
+			shader: Shader::from_program(object, false),
+			inputs,
+			outputs,
+			kind: PhantomData,
+		})
+	}
+
+	/// the underlying program, ie. for reflection or [`Shader::validate()`].
+	pub fn get_shader(&self) -> &Shader {
+		&self.shader
+	}
+	/// an input of the stage. always `None` without OpenGL 4.3.
+	pub fn get_input(&self, name: &str) -> Option<&ShaderVarying> {
+		self.inputs.get(name)
+	}
+	/// an output of the stage. always `None` without OpenGL 4.3.
+	pub fn get_output(&self, name: &str) -> Option<&ShaderVarying> {
+		self.outputs.get(name)
+	}
+
+	pub fn handle(&self) -> &lykoi_gl::ProgramObject {
+		self.shader.handle()
+	}
+	/// defer deleting the program to `queue`, see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.shader.release_into(queue);
+	}
+}
+
+/// the user defined inputs or outputs of a linked program.
+fn reflect_interface(object: &lykoi_gl::ProgramObject, interface: u32) -> HashMap<String, ShaderVarying> {
+	let amount = ext::get_program_interface_iv(object, interface, gl::ACTIVE_RESOURCES) as u32;
+
+	let mut out = HashMap::new();
+
+	for index in 0..amount {
+		let mut name = ext::get_program_resource_name(object, interface, index);
+		// built-ins like gl_Position are matched by the driver.
+		if name.starts_with("gl_") {
+			continue;
+		}
+		if name.ends_with("[0]") {
+			name.truncate(name.len() - 3);
+		}
+		let kind = ext::get_program_resource_iv(object, interface, index, gl::TYPE);
+		let size = ext::get_program_resource_iv(object, interface, index, gl::ARRAY_SIZE);
+		let location = ext::get_program_resource_iv(object, interface, index, gl::LOCATION);
+		out.insert(name, ShaderVarying {
+			kind: GlslType::from_gl(kind as u32),
+			size: size.max(1) as usize,
+			location: u32::try_from(location).ok(),
+		});
+	}
+
+	out
+}
+
+
+/**
+a vertex and a fragment [`SeparableShader`] combined into a [`Program`], which draw calls take
+like a [`Shader`].
+
+```ignore
+let sprite = SeparableShader::<ShaderPartVertex>::new_src(SPRITE_VERT)?;
+let flat = SeparableShader::<ShaderPartFragment>::new_src(FLAT_FRAG)?;
+let lit = SeparableShader::<ShaderPartFragment>::new_src(LIT_FRAG)?;
+
+let sprite_flat = ProgramPipeline::new(&sprite, &flat)?;
+let sprite_lit = ProgramPipeline::new(&sprite, &lit)?;
+
+draw.draw(None, &model, &sprite_lit, UniformItem::new().add("u_light", light), Config::new());
+```
+
+uniforms are set in whichever stages have them, so a uniform declared in both stages gets the
+same value in both.
+*/
+pub struct ProgramPipeline<'a> {
+	object: Owned<ext::PipelineObject>,
+	vertex: &'a SeparableShader<ShaderPartVertex>,
+	fragment: &'a SeparableShader<ShaderPartFragment>,
+}
+impl<'a> ProgramPipeline<'a> {
+	/// fails if an input of `fragment` isn't written by `vertex`, matched by location if it
+	/// has one and by name otherwise, or if the types don't agree. this check needs OpenGL 4.3,
+	/// and is skipped without it.
+	pub fn new(
+		vertex: &'a SeparableShader<ShaderPartVertex>,
+		fragment: &'a SeparableShader<ShaderPartFragment>,
+	) -> Result<Self, Error> {
+		if ext::gl_version() >= VERSION_INTERFACE {
+			Self::check_interface(vertex, fragment)?;
+		}
+
+		let object = ext::gen_program_pipeline();
+		ext::use_program_stages(&object, gl::VERTEX_SHADER_BIT, vertex.handle());
+		ext::use_program_stages(&object, gl::FRAGMENT_SHADER_BIT, fragment.handle());
+
+		Ok(Self {
+			object: Owned::new(object),
+			vertex,
+			fragment,
+		})
+	}
+
+	fn check_interface(
+		vertex: &SeparableShader<ShaderPartVertex>,
+		fragment: &SeparableShader<ShaderPartFragment>,
+	) -> Result<(), Error> {
+		for (name, input) in &fragment.inputs {
+			let output = match input.location {
+				Some(location) => vertex.outputs.values().find(|v| v.location == Some(location)),
+				None => vertex.outputs.get(name),
+			};
+			let Some(output) = output else {
+				return Err(Error::InterfaceMissing(name.clone()));
+			};
+			if output.kind != input.kind {
+				return Err(Error::InterfaceMismatch {
+					name: name.clone(),
+					expected: input.kind,
+					got: output.kind,
+				});
+			}
+		}
+		Ok(())
+	}
+
+	pub fn get_vertex(&self) -> &SeparableShader<ShaderPartVertex> {
+		self.vertex
+	}
+	pub fn get_fragment(&self) -> &SeparableShader<ShaderPartFragment> {
+		self.fragment
+	}
+
+	/// defer deleting the pipeline object to `queue`, see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.object.release_into(queue);
+	}
+}
+impl Program for ProgramPipeline<'_> {
+	fn bind(&self) {
+		// a program in use takes precedence over the bound pipeline.
+		ext::unuse_program();
+		ext::bind_program_pipeline(&self.object);
+	}
+	fn vertex_stage(&self) -> &Shader {
+		self.vertex.get_shader()
+	}
+	fn visit_stages(&self, mut callback: impl FnMut(&Shader)) {
+		for shader in [self.vertex.get_shader(), self.fragment.get_shader()] {
+			ext::active_shader_program(&self.object, shader.handle());
+			callback(shader);
+		}
+	}
+}
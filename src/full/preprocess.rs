@@ -0,0 +1,267 @@
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::{Error, Shader, ShaderPartFragment, ShaderPartVertex};
+
+
+/// where a [`Preprocessor`] loads files from.
+pub trait ShaderSource {
+	/// the contents of the file at `path`, or `None` if it doesn't exist.
+	///
+	/// paths are always relative to the root of the source, separated by `/`.
+	fn load(&self, path: &str) -> Option<String>;
+}
+
+impl ShaderSource for HashMap<String, String> {
+	fn load(&self, path: &str) -> Option<String> {
+		self.get(path).cloned()
+	}
+}
+impl<'a> ShaderSource for HashMap<&'a str, &'a str> {
+	fn load(&self, path: &str) -> Option<String> {
+		self.get(path).map(|v| v.to_string())
+	}
+}
+
+/// loads files from a directory.
+#[derive(Debug, Clone)]
+pub struct ShaderSourceDir {
+	root: PathBuf,
+}
+impl ShaderSourceDir {
+	pub fn new(root: impl Into<PathBuf>) -> Self {
+		Self {
+			root: root.into(),
+		}
+	}
+
+	pub fn root(&self) -> &Path {
+		&self.root
+	}
+}
+impl ShaderSource for ShaderSourceDir {
+	fn load(&self, path: &str) -> Option<String> {
+		std::fs::read_to_string(self.root.join(path)).ok()
+	}
+}
+
+
+/// output of a [`Preprocessor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Preprocessed {
+	/// the expanded source, ready to compile.
+	pub src: String,
+	/// every file that went into `src`. the source string number of a `#line` directive is
+	/// the index of its file in here, so `files[0]` is the file that was processed.
+	pub files: Vec<String>,
+}
+impl Preprocessed {
+	/// the path of the file with the source string number `id`.
+	pub fn file(&self, id: usize) -> Option<&str> {
+		self.files.get(id).map(|v| v.as_str())
+	}
+}
+
+
+/**
+expands `#include "file"` directives and injects `#define`s into glsl sources.
+
+includes are resolved relative to the including file, or relative to the root of the
+[`ShaderSource`] if they start with `/`. after each include, a `#line` directive brings the
+line numbers back in sync with the including file, so compile errors can be mapped back to
+their file with [`Preprocessed::files`]. a `#line` that would be directly followed by another
+one is left out, since some drivers don't apply the second. `#version` must be in the processed
+file, and is dropped from included ones.
+
+```
+use std::collections::HashMap;
+use lykoi_drive::Preprocessor;
+
+let mut files = HashMap::new();
+files.insert("main.frag", "#version 330 core\n#include \"lib/color.glsl\"\nvoid main() {}\n");
+files.insert("lib/color.glsl", "vec3 srgb(vec3 c) { return pow(c, vec3(GAMMA)); }\n");
+
+let out = Preprocessor::new(files)
+    .define("GAMMA", "2.2")
+    .process("main.frag")
+    .unwrap();
+
+assert_eq!(out.files, ["main.frag", "lib/color.glsl"]);
+assert_eq!(out.src, "\
+#version 330 core
+#define GAMMA 2.2
+#line 1 1
+vec3 srgb(vec3 c) { return pow(c, vec3(GAMMA)); }
+#line 3 0
+void main() {}
+");
+```
+*/
+pub struct Preprocessor<S: ShaderSource> {
+	source: S,
+	defines: Vec<(String, String)>,
+}
+impl<S: ShaderSource> Preprocessor<S> {
+	pub fn new(source: S) -> Self {
+		Self {
+			source,
+			defines: Vec::new(),
+		}
+	}
+
+	/// injects `#define name value` right after `#version`.
+	pub fn define(mut self, name: &str, value: &str) -> Self {
+		self.defines.push((name.to_string(), value.to_string()));
+		self
+	}
+
+	pub fn get_source(&self) -> &S {
+		&self.source
+	}
+
+	/// loads and expands the file at `path`.
+	pub fn process(&self, path: &str) -> Result<Preprocessed, Error> {
+		let src = self.source.load(path)
+			.ok_or_else(|| Error::SourceMissing(path.to_string()))?;
+		self.process_src(path, &src)
+	}
+
+	/// expands `src`, as if it was the file at `path`.
+	pub fn process_src(&self, path: &str, src: &str) -> Result<Preprocessed, Error> {
+		self.process_src_with(path, src, &[])
+	}
+
+	/// like [`Self::process()`], injecting `defines` after the preprocessor's own.
+	pub(crate) fn process_with(&self, path: &str, defines: &[(String, String)]) -> Result<Preprocessed, Error> {
+		let src = self.source.load(path)
+			.ok_or_else(|| Error::SourceMissing(path.to_string()))?;
+		self.process_src_with(path, &src, defines)
+	}
+
+	fn process_src_with(&self, path: &str, src: &str, defines: &[(String, String)]) -> Result<Preprocessed, Error> {
+		let mut out = Preprocessed {
+			src: String::new(),
+			files: vec![path.to_string()],
+		};
+		let mut stack = vec![path.to_string()];
+
+		let has_version = src.lines().any(|v| v.trim_start().starts_with("#version"));
+		if !has_version {
+			self.emit_defines(defines, &mut out.src);
+		}
+
+		self.expand(src, 0, true, defines, &mut stack, &mut out)?;
+		Ok(out)
+	}
+
+	/// processes and compiles `vertex` and `fragment` into a [`Shader`].
+	pub fn shader(&self, vertex: &str, fragment: &str) -> Result<Shader, Error> {
+		let vertex = ShaderPartVertex::new_preprocessed(&self.process(vertex)?)?;
+		let fragment = ShaderPartFragment::new_preprocessed(&self.process(fragment)?)?;
+		Shader::new(vertex, fragment)
+	}
+
+	fn emit_defines(&self, defines: &[(String, String)], out: &mut String) {
+		for (name, value) in self.defines.iter().chain(defines) {
+			out.push_str(&format!("#define {} {}\n", name, value));
+		}
+	}
+
+	fn expand(
+		&self,
+		src: &str,
+		id: usize,
+		top: bool,
+		defines: &[(String, String)],
+		stack: &mut Vec<String>,
+		out: &mut Preprocessed,
+	) -> Result<(), Error> {
+		let path = stack.last().unwrap().clone();
+		let mut version = false;
+
+		// the `#line` to emit before the next line, held back so an `#include` right after it
+		// only emits its own. nothing may come before the `#version` of the processed file.
+		let has_version = top && src.lines().any(|v| v.trim_start().starts_with("#version"));
+		let mut directive = (!has_version).then(|| format!("#line 1 {}\n", id));
+
+		for (i, line) in src.lines().enumerate() {
+			let number = i + 1;
+			let trimmed = line.trim_start();
+
+			if trimmed.starts_with("#version") {
+				if top && !version {
+					version = true;
+					out.src.push_str(line);
+					out.src.push('\n');
+					self.emit_defines(defines, &mut out.src);
+					directive = Some(format!("#line {} {}\n", number + 1, id));
+				} else {
+					// keep the line count intact.
+					out.src.extend(directive.take());
+					out.src.push('\n');
+				}
+				continue;
+			}
+
+			let Some(rest) = trimmed.strip_prefix("#include") else {
+				out.src.extend(directive.take());
+				out.src.push_str(line);
+				out.src.push('\n');
+				continue;
+			};
+
+			let Some(include) = rest.trim()
+				.strip_prefix('"')
+				.and_then(|v| v.strip_suffix('"'))
+			else {
+				return Err(Error::IncludeSyntax { file: path, line: number });
+			};
+
+			let include = resolve(&path, include);
+			if stack.contains(&include) {
+				return Err(Error::IncludeCycle(include));
+			}
+			let src = self.source.load(&include)
+				.ok_or_else(|| Error::SourceMissing(include.clone()))?;
+
+			let include_id = match out.files.iter().position(|v| *v == include) {
+				Some(v) => v,
+				None => {
+					out.files.push(include.clone());
+					out.files.len() - 1
+				},
+			};
+
+			stack.push(include);
+			self.expand(&src, include_id, false, defines, stack, out)?;
+			stack.pop();
+			directive = Some(format!("#line {} {}\n", number + 1, id));
+		}
+
+		Ok(())
+	}
+}
+
+/// resolves `path` relative to the file `from`.
+fn resolve(from: &str, path: &str) -> String {
+	let mut parts: Vec<&str> = if path.starts_with('/') {
+		Vec::new()
+	} else {
+		let mut parts: Vec<&str> = from.split('/').collect();
+		parts.pop();
+		parts
+	};
+
+	for part in path.trim_start_matches('/').split('/') {
+		match part {
+			"" | "." => (),
+			".." => {
+				parts.pop();
+			},
+			part => parts.push(part),
+		}
+	}
+
+	parts.join("/")
+}
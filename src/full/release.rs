@@ -0,0 +1,145 @@
+
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+
+use super::ext;
+
+
+/// an OpenGL object waiting to be deleted.
+// the objects are only held on to so they can be dropped later.
+#[allow(dead_code)]
+#[derive(Debug)]
+pub(crate) enum Garbage {
+	Buffer(lykoi_gl::BufferObject),
+	VertexArray(lykoi_gl::VertexArrayObject),
+	Texture(lykoi_gl::TextureObject),
+	Framebuffer(lykoi_gl::FramebufferObject),
+	Renderbuffer(lykoi_gl::RenderbufferObject),
+	Program(lykoi_gl::ProgramObject),
+	Shader(ext::ShaderObject),
+	Pipeline(ext::PipelineObject),
+}
+
+impl From<lykoi_gl::BufferObject> for Garbage {
+	fn from(value: lykoi_gl::BufferObject) -> Self {
+		Garbage::Buffer(value)
+	}
+}
+impl From<lykoi_gl::VertexArrayObject> for Garbage {
+	fn from(value: lykoi_gl::VertexArrayObject) -> Self {
+		Garbage::VertexArray(value)
+	}
+}
+impl From<lykoi_gl::TextureObject> for Garbage {
+	fn from(value: lykoi_gl::TextureObject) -> Self {
+		Garbage::Texture(value)
+	}
+}
+impl From<lykoi_gl::FramebufferObject> for Garbage {
+	fn from(value: lykoi_gl::FramebufferObject) -> Self {
+		Garbage::Framebuffer(value)
+	}
+}
+impl From<lykoi_gl::RenderbufferObject> for Garbage {
+	fn from(value: lykoi_gl::RenderbufferObject) -> Self {
+		Garbage::Renderbuffer(value)
+	}
+}
+impl From<lykoi_gl::ProgramObject> for Garbage {
+	fn from(value: lykoi_gl::ProgramObject) -> Self {
+		Garbage::Program(value)
+	}
+}
+impl From<ext::ShaderObject> for Garbage {
+	fn from(value: ext::ShaderObject) -> Self {
+		Garbage::Shader(value)
+	}
+}
+impl From<ext::PipelineObject> for Garbage {
+	fn from(value: ext::PipelineObject) -> Self {
+		Garbage::Pipeline(value)
+	}
+}
+
+
+/**
+queue of OpenGL objects to delete later.
+
+every wrapper (ie. [`crate::Texture`], [`crate::VertexBuffer`]) deletes its objects as soon
+as it's dropped, which has to happen while the context that created it is current. if that
+can't be guaranteed, ie. because the resource is dropped on another thread or while a
+different context is current, opt into `release_into()` on the resource. its objects are
+then pushed onto this queue when dropped, and actually deleted by [`crate::Draw::collect()`].
+
+the queue is cheap to clone, and all clones refer to the same queue.
+*/
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseQueue {
+	list: Arc<Mutex<Vec<Garbage>>>,
+}
+impl ReleaseQueue {
+	pub fn new() -> Self {
+		Self {
+			list: Arc::new(Mutex::new(Vec::new())),
+		}
+	}
+
+	/// amount of objects waiting to be deleted.
+	pub fn len(&self) -> usize {
+		self.list.lock().unwrap().len()
+	}
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	fn push(&self, garbage: Garbage) {
+		self.list.lock().unwrap().push(garbage);
+	}
+
+	/// deletes every queued object. the context that created them must be current.
+	pub(crate) fn collect(&self) {
+		let list = std::mem::take(&mut *self.list.lock().unwrap());
+		drop(list);
+	}
+}
+
+
+/// an OpenGL object that either deletes itself on drop, or pushes itself onto a [`ReleaseQueue`].
+#[derive(Debug)]
+pub(crate) struct Owned<T: Into<Garbage>> {
+	object: ManuallyDrop<T>,
+	queue: Option<ReleaseQueue>,
+}
+impl<T: Into<Garbage>> Owned<T> {
+	pub fn new(object: T) -> Self {
+		Self {
+			object: ManuallyDrop::new(object),
+			queue: None,
+		}
+	}
+
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.queue = Some(queue.clone());
+	}
+
+	pub fn queue(&self) -> Option<&ReleaseQueue> {
+		self.queue.as_ref()
+	}
+}
+impl<T: Into<Garbage>> Deref for Owned<T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.object
+	}
+}
+impl<T: Into<Garbage>> Drop for Owned<T> {
+	fn drop(&mut self) {
+		// safety: `object` is never touched again after this
+		let object = unsafe { ManuallyDrop::take(&mut self.object) };
+		if let Some(ref queue) = self.queue {
+			queue.push(object.into());
+		}
+	}
+}
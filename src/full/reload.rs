@@ -0,0 +1,180 @@
+
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::{Error, Preprocessor, Program, ReleaseQueue, Shader, ShaderPartFragment, ShaderPartVertex, ShaderSourceDir};
+
+
+/// a file a [`ReloadableShader`] was built from, with its modification time at the time.
+struct Watched {
+	path: PathBuf,
+	time: Option<SystemTime>,
+}
+
+/**
+a [`Shader`] loaded from disk, which recompiles itself when its files change.
+
+[`Self::poll()`] checks the modification times of every file that went into the shader
+(including `#include`s, see [`Preprocessor`]), and rebuilds it if any of them changed. if the
+rebuild fails, the previous program stays in use and the error is kept in [`Self::get_error()`],
+so a typo never takes down the app.
+
+derefs to the current [`Shader`], and can be passed to draw calls as is.
+
+```ignore
+let mut shader = ReloadableShader::new(
+    Preprocessor::new(ShaderSourceDir::new("assets/shaders")),
+    "sprite.vert",
+    "sprite.frag",
+)?;
+
+loop {
+    if shader.poll() {
+        if let Some(e) = shader.get_error() {
+            eprintln!("{}", e);
+        }
+    }
+    draw.draw(None, &model, &shader, UniformItem::new(), Config::new());
+}
+```
+*/
+pub struct ReloadableShader {
+	preprocessor: Preprocessor<ShaderSourceDir>,
+	vertex: String,
+	fragment: String,
+	shader: Shader,
+	files: Vec<Watched>,
+	error: Option<Error>,
+	queue: Option<ReleaseQueue>,
+}
+impl ReloadableShader {
+	/// builds the shader from the files at `vertex` and `fragment`. fails if that doesn't
+	/// work the first time, since there's no previous program to fall back to.
+	pub fn new(preprocessor: Preprocessor<ShaderSourceDir>, vertex: &str, fragment: &str) -> Result<Self, Error> {
+		let (shader, files) = Self::build(&preprocessor, vertex, fragment)?;
+		Ok(Self {
+			preprocessor,
+			vertex: vertex.to_string(),
+			fragment: fragment.to_string(),
+			shader,
+			files,
+			error: None,
+			queue: None,
+		})
+	}
+
+	fn build(
+		preprocessor: &Preprocessor<ShaderSourceDir>,
+		vertex: &str,
+		fragment: &str,
+	) -> Result<(Shader, Vec<Watched>), Error> {
+		let root = preprocessor.get_source().root();
+
+		let mut vertex_src = preprocessor.process(vertex)?;
+		let mut fragment_src = preprocessor.process(fragment)?;
+
+		// the times are taken before the sources that get built are read, so an edit in between
+		// shows up as a change on the next poll instead of being missed. the first read is only
+		// to find out which files to look at.
+		let files = loop {
+			let mut files: Vec<Watched> = Vec::new();
+			for file in vertex_src.files.iter().chain(fragment_src.files.iter()) {
+				let path = root.join(file);
+				if !files.iter().any(|v| v.path == path) {
+					let time = modified(&path);
+					files.push(Watched { path, time });
+				}
+			}
+
+			let vertex_next = preprocessor.process(vertex)?;
+			let fragment_next = preprocessor.process(fragment)?;
+			// an edit changed the includes, so different files have to be looked at.
+			let same = vertex_next.files == vertex_src.files && fragment_next.files == fragment_src.files;
+			vertex_src = vertex_next;
+			fragment_src = fragment_next;
+			if same {
+				break files;
+			}
+		};
+
+		let shader = Shader::new(
+			ShaderPartVertex::new_preprocessed(&vertex_src)?,
+			ShaderPartFragment::new_preprocessed(&fragment_src)?,
+		)?;
+
+		Ok((shader, files))
+	}
+
+	/// rebuilds the shader if any of its files changed since the last build. returns whether
+	/// a rebuild was attempted, check [`Self::get_error()`] to see if it worked.
+	pub fn poll(&mut self) -> bool {
+		let changed = self.files.iter().any(|v| modified(&v.path) != v.time);
+		if changed {
+			self.reload();
+		}
+		changed
+	}
+
+	/// rebuilds the shader, even if nothing changed.
+	pub fn reload(&mut self) {
+		// taken before building, for the same reason as in `build()`.
+		let times: Vec<_> = self.files.iter().map(|v| modified(&v.path)).collect();
+
+		match Self::build(&self.preprocessor, &self.vertex, &self.fragment) {
+			Ok((mut shader, files)) => {
+				if let Some(ref queue) = self.queue {
+					shader.release_into(queue);
+				}
+				self.shader = shader;
+				self.files = files;
+				self.error = None;
+			},
+			Err(e) => {
+				// don't retry until the files change again.
+				for (file, time) in self.files.iter_mut().zip(times) {
+					file.time = time;
+				}
+				self.error = Some(e);
+			},
+		}
+	}
+
+	/// the error of the last rebuild, if it failed.
+	pub fn get_error(&self) -> Option<&Error> {
+		self.error.as_ref()
+	}
+
+	pub fn get_shader(&self) -> &Shader {
+		&self.shader
+	}
+
+	/// defer deleting the program, and every program it's replaced by, to `queue`.
+	/// see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.shader.release_into(queue);
+		self.queue = Some(queue.clone());
+	}
+}
+impl Deref for ReloadableShader {
+	type Target = Shader;
+
+	fn deref(&self) -> &Self::Target {
+		&self.shader
+	}
+}
+impl Program for ReloadableShader {
+	fn bind(&self) {
+		self.shader.bind();
+	}
+	fn vertex_stage(&self) -> &Shader {
+		&self.shader
+	}
+	fn visit_stages(&self, callback: impl FnMut(&Shader)) {
+		self.shader.visit_stages(callback);
+	}
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+	std::fs::metadata(path).and_then(|v| v.modified()).ok()
+}
@@ -1,100 +1,477 @@
 
 use std::marker::PhantomData;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use super::{ext, Error, GlslType, Owned, Preprocessed, ReleaseQueue, UniformList, UniformTypes, VertexFormat};
 
-pub struct ShaderPartFragment;
-impl ShaderPartFragment {
-	pub fn new(src: &str) -> Result<ShaderPart<Self>, String> {
-		ShaderPart::new(src, lykoi_gl::ShaderType::FragmentShader)
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShaderStage {
+	Vertex,
+	Fragment,
+	Geometry,
+	TessControl,
+	TessEval,
+	Compute,
+}
+impl ShaderStage {
+	fn to(&self) -> u32 {
+		match self {
+			ShaderStage::Vertex => gl::VERTEX_SHADER,
+			ShaderStage::Fragment => gl::FRAGMENT_SHADER,
+			ShaderStage::Geometry => gl::GEOMETRY_SHADER,
+			ShaderStage::TessControl => gl::TESS_CONTROL_SHADER,
+			ShaderStage::TessEval => gl::TESS_EVALUATION_SHADER,
+			ShaderStage::Compute => gl::COMPUTE_SHADER,
+		}
 	}
 }
 
-pub struct ShaderPartVertex;
-impl ShaderPartVertex {
-	pub fn new(src: &str) -> Result<ShaderPart<Self>, String> {
-		ShaderPart::new(src, lykoi_gl::ShaderType::VertexShader)
-	}
+macro_rules! shader_part {
+	($name:ident, $stage:ident) => {
+		pub struct $name;
+		impl $name {
+			pub fn new(src: &str) -> Result<ShaderPart<Self>, Error> {
+				ShaderPart::new(src, ShaderStage::$stage, &[])
+			}
+			/// like [`Self::new()`], but compile errors refer back to the preprocessed files.
+			pub fn new_preprocessed(src: &Preprocessed) -> Result<ShaderPart<Self>, Error> {
+				ShaderPart::new(&src.src, ShaderStage::$stage, &src.files)
+			}
+		}
+	};
 }
+shader_part!(ShaderPartVertex, Vertex);
+shader_part!(ShaderPartFragment, Fragment);
+shader_part!(ShaderPartGeometry, Geometry);
+shader_part!(ShaderPartTessControl, TessControl);
+shader_part!(ShaderPartTessEval, TessEval);
+shader_part!(ShaderPartCompute, Compute);
 
 pub struct ShaderPart<T> {
-	object: lykoi_gl::ShaderObject,
+	object: Owned<ext::ShaderObject>,
 	kind: PhantomData<T>,
 }
 impl<T> ShaderPart<T> {
-	fn new(src: &str, kind: lykoi_gl::ShaderType) -> Result<Self, String> {
-		let object = lykoi_gl::create_shader(kind).unwrap();
-		lykoi_gl::shader_source(&object, [src]);
-		lykoi_gl::compile_shader(&object);
-
-		if !lykoi_gl::get_shader_compile_status(&object) {
-			let string = lykoi_gl::get_shader_info_log(&object);
-			return Err(string);
+	fn new(src: &str, stage: ShaderStage, files: &[String]) -> Result<Self, Error> {
+		let object = ext::create_shader(stage.to()).ok_or(Error::Create("shader"))?;
+		ext::shader_source(&object, src);
+		ext::compile_shader(&object);
+
+		if !ext::get_shader_compile_status(&object) {
+			let log = ext::get_shader_info_log(&object);
+			return Err(Error::Compile {
+				stage,
+				log,
+				files: files.to_vec(),
+			});
 		}
 
 		Ok(Self {
-			object,
+			object: Owned::new(object),
 			kind: PhantomData,
 		})
 	}
+
+	/// defer deleting the shader object to `queue`, see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.object.release_into(queue);
+	}
+
+	pub(crate) fn handle(&self) -> &ext::ShaderObject {
+		&self.object
+	}
+}
+
+/**
+links [`ShaderPart`]s into a [`Shader`].
+
+a vertex and a fragment stage are always needed. geometry and tessellation stages are optional,
+and a tessellation control stage can only be added together with an evaluation stage. anything
+else that doesn't fit together, ie. mismatched interfaces between stages, fails at link time.
+
+shaders with tessellation stages have to be drawn with [`crate::ConfigPrimitive::Patches`].
+*/
+pub struct ShaderBuilder {
+	vertex: ShaderPart<ShaderPartVertex>,
+	fragment: ShaderPart<ShaderPartFragment>,
+	geometry: Option<ShaderPart<ShaderPartGeometry>>,
+	tessellation: Option<(Option<ShaderPart<ShaderPartTessControl>>, ShaderPart<ShaderPartTessEval>)>,
+}
+impl ShaderBuilder {
+	pub fn new(vertex: ShaderPart<ShaderPartVertex>, fragment: ShaderPart<ShaderPartFragment>) -> Self {
+		Self {
+			vertex,
+			fragment,
+			geometry: None,
+			tessellation: None,
+		}
+	}
+
+	pub fn geometry(mut self, geometry: ShaderPart<ShaderPartGeometry>) -> Self {
+		self.geometry = Some(geometry);
+		self
+	}
+
+	/// adds tessellation stages. without a control stage, the patch size and the default
+	/// tessellation levels are used.
+	pub fn tessellation(
+		mut self,
+		control: Option<ShaderPart<ShaderPartTessControl>>,
+		eval: ShaderPart<ShaderPartTessEval>,
+	) -> Self {
+		self.tessellation = Some((control, eval));
+		self
+	}
+
+	pub fn build(self) -> Result<Shader, Error> {
+		self.link(lykoi_gl::create_program().map_err(|_| Error::Create("program"))?)
+	}
+
+	/// links the stages into `object`, which may have had parameters set on it beforehand.
+	pub(crate) fn link(self, object: lykoi_gl::ProgramObject) -> Result<Shader, Error> {
+		ext::attach_shader(&object, &self.vertex.object);
+		ext::attach_shader(&object, &self.fragment.object);
+		if let Some(ref geometry) = self.geometry {
+			ext::attach_shader(&object, &geometry.object);
+		}
+		if let Some((ref control, ref eval)) = self.tessellation {
+			if let Some(control) = control {
+				ext::attach_shader(&object, &control.object);
+			}
+			ext::attach_shader(&object, &eval.object);
+		}
+		lykoi_gl::link_program(&object);
+
+		if !lykoi_gl::get_program_link_status(&object) {
+			let log = lykoi_gl::get_program_info_log(&object);
+			return Err(Error::Link(log));
+		}
+
+		Ok(Shader::from_program(object, self.tessellation.is_some()))
+	}
+}
+
+/// an active uniform in a linked [`Shader`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShaderUniform {
+	pub kind: GlslType,
+	/// amount of array elements, `1` if it isn't an array.
+	pub size: usize,
+	/// the uniform's location, looked up once when the shader is linked.
+	pub location: i32,
+}
+
+/// an active vertex attribute in a linked [`Shader`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShaderAttribute {
+	pub kind: GlslType,
+	/// amount of array elements, `1` if it isn't an array.
+	pub size: usize,
+	/// the first location the attribute takes up.
+	pub location: u32,
+}
+
+/// an active uniform or storage block in a linked [`Shader`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShaderBlock {
+	/// the block's index in the program.
+	pub index: u32,
+	/// minimum size of a buffer bound to the block, in bytes. for a storage block ending in a
+	/// runtime sized array, that's the size of the fields before the array.
+	pub size: usize,
+}
+
+/// result of [`Shader::validate()`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UniformReport {
+	/// names given that the shader has no active uniform for.
+	pub missing: Vec<String>,
+	/// names given a value that doesn't fit, with the type the shader expects.
+	pub mismatched: Vec<(String, GlslType)>,
+	/// uniform and storage blocks given a buffer that's too small, with the size of the block.
+	pub undersized: Vec<(String, usize)>,
+	/// active uniforms and blocks of the shader that weren't given, sorted by name.
+	pub unset: Vec<String>,
+}
+impl UniformReport {
+	/// whether the shader and the uniforms agree completely.
+	pub fn is_ok(&self) -> bool {
+		self.missing.is_empty()
+			&& self.mismatched.is_empty()
+			&& self.undersized.is_empty()
+			&& self.unset.is_empty()
+	}
+}
+
+/**
+something draw calls can run: a [`Shader`], or a [`crate::ProgramPipeline`] of separately
+linked stages.
+*/
+pub trait Program {
+	/// makes the program current for drawing.
+	fn bind(&self);
+	/// the program of the vertex stage, which vertex formats and primitives are checked against.
+	fn vertex_stage(&self) -> &Shader;
+	/// calls `callback` with the program of every stage, each made the target of `glUniform*`
+	/// while it's visited. the program has to be bound.
+	fn visit_stages(&self, callback: impl FnMut(&Shader));
 }
 
 pub struct Shader {
-	object: lykoi_gl::ProgramObject,
-	uniforms: HashMap<String, lykoi_gl::UniformTypes>,
+	object: Owned<lykoi_gl::ProgramObject>,
+	uniforms: HashMap<String, ShaderUniform>,
+	blocks: HashMap<String, ShaderBlock>,
+	storage: HashMap<String, ShaderBlock>,
+	attributes: HashMap<String, ShaderAttribute>,
+	tessellation: bool,
 }
 impl Shader {
 	pub fn new(
 		vertex: ShaderPart<ShaderPartVertex>,
 		fragment: ShaderPart<ShaderPartFragment>
-	) -> Result<Self, String> {
-		let object = lykoi_gl::create_program().unwrap();
+	) -> Result<Self, Error> {
+		ShaderBuilder::new(vertex, fragment).build()
+	}
+	/// see [`ShaderBuilder`].
+	pub fn builder(
+		vertex: ShaderPart<ShaderPartVertex>,
+		fragment: ShaderPart<ShaderPartFragment>
+	) -> ShaderBuilder {
+		ShaderBuilder::new(vertex, fragment)
+	}
 
-		lykoi_gl::attach_shader(&object, &fragment.object);
-		lykoi_gl::attach_shader(&object, &vertex.object);
-		lykoi_gl::link_program(&object);
+	/// reflects the interface of a linked program.
+	pub(crate) fn from_program(object: lykoi_gl::ProgramObject, tessellation: bool) -> Self {
+		let amount = lykoi_gl::get_program_active_uniforms(&object);
 
-		if !lykoi_gl::get_program_link_status(&object) {
-			let string = lykoi_gl::get_program_info_log(&object);
-			return Err(string);
+		let mut uniforms = HashMap::new();
+
+		for id in 0..amount {
+			let (mut name, kind, size) = ext::get_active_uniform(&object, id);
+			// arrays are reported as "name[0]", but set through "name".
+			if name.ends_with("[0]") {
+				name.truncate(name.len() - 3);
+			}
+			// uniforms inside a block have no location, and can't be set directly.
+			let Some(location) = lykoi_gl::get_uniform_location(&object, &name) else {
+				continue;
+			};
+			uniforms.insert(name, ShaderUniform {
+				kind: GlslType::from_gl(kind),
+				size,
+				location: location.get() as i32,
+			});
 		}
 
-		let amount = lykoi_gl::get_program_active_uniforms(&object);
+		let amount = ext::get_program_iv(&object, gl::ACTIVE_UNIFORM_BLOCKS) as u32;
 
-		let mut uniforms = HashMap::new();
+		let mut blocks = HashMap::new();
+
+		for index in 0..amount {
+			let name = ext::get_active_uniform_block_name(&object, index);
+			let size = ext::get_active_uniform_block_iv(&object, index, gl::UNIFORM_BLOCK_DATA_SIZE);
+			blocks.insert(name, ShaderBlock {
+				index,
+				size: size as usize,
+			});
+		}
+
+		let mut storage = HashMap::new();
+
+		// storage blocks can only be queried through the 4.3 program interface api.
+		if ext::gl_version() >= (4, 3) {
+			let amount = ext::get_program_interface_iv(&object, gl::SHADER_STORAGE_BLOCK, gl::ACTIVE_RESOURCES) as u32;
+
+			for index in 0..amount {
+				let name = ext::get_program_resource_name(&object, gl::SHADER_STORAGE_BLOCK, index);
+				let mut size = ext::get_program_resource_iv(&object, gl::SHADER_STORAGE_BLOCK, index, gl::BUFFER_DATA_SIZE);
+
+				// a runtime sized array at the end can have any length, even none, so only the
+				// fields before it have to fit.
+				// an array of structs reports it through its top level size, while some drivers
+				// only report it through the array size for arrays of basic types.
+				for variable in ext::get_program_resource_active_variables(&object, gl::SHADER_STORAGE_BLOCK, index) {
+					let runtime = [gl::TOP_LEVEL_ARRAY_SIZE, gl::ARRAY_SIZE].into_iter()
+						.any(|prop| ext::get_program_resource_iv(&object, gl::BUFFER_VARIABLE, variable, prop) == 0);
+					if runtime {
+						let offset = ext::get_program_resource_iv(&object, gl::BUFFER_VARIABLE, variable, gl::OFFSET);
+						size = size.min(offset);
+					}
+				}
+
+				storage.insert(name, ShaderBlock {
+					index,
+					size: size as usize,
+				});
+			}
+		}
+
+		let amount = ext::get_program_iv(&object, gl::ACTIVE_ATTRIBUTES) as u32;
+
+		let mut attributes = HashMap::new();
 
 		for id in 0..amount {
-			let (name, kind, _) = lykoi_gl::get_active_uniform(&object, id);
-			uniforms.insert(name, kind);
+			let (name, kind, size) = ext::get_active_attrib(&object, id);
+			// built-ins like gl_VertexID aren't fed by a buffer.
+			let Some(location) = ext::get_attrib_location(&object, &name) else {
+				continue;
+			};
+			attributes.insert(name, ShaderAttribute {
+				kind: GlslType::from_gl(kind),
+				size,
+				location,
+			});
 		}
 
-		Ok(Self {
-			object,
+		Self {
+			object: Owned::new(object),
 			uniforms,
-		})
+			blocks,
+			storage,
+			attributes,
+			tessellation,
+		}
 	}
-	pub fn new_src(vertex_src: &str, fragment_src: &str) -> Result<Self, String> {
-		let shader_vs = Shader::new_vertex(vertex_src).unwrap();
-		let shader_fs = Shader::new_fragment(fragment_src).unwrap();
+	pub fn new_src(vertex_src: &str, fragment_src: &str) -> Result<Self, Error> {
+		let shader_vs = Shader::new_vertex(vertex_src)?;
+		let shader_fs = Shader::new_fragment(fragment_src)?;
 		Self::new(shader_vs, shader_fs)
 	}
-	pub fn new_fragment(src: &str) -> Result<ShaderPart<ShaderPartFragment>, String> {
+	pub fn new_fragment(src: &str) -> Result<ShaderPart<ShaderPartFragment>, Error> {
 		ShaderPartFragment::new(src)
 	}
-	pub fn new_vertex(src: &str) -> Result<ShaderPart<ShaderPartVertex>, String> {
+	pub fn new_vertex(src: &str) -> Result<ShaderPart<ShaderPartVertex>, Error> {
 		ShaderPartVertex::new(src)
 	}
 
+	/// defer deleting the program to `queue`, see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.object.release_into(queue);
+	}
+
 	pub fn handle(&self) -> &lykoi_gl::ProgramObject {
 		&self.object
 	}
-	pub fn get_uniform(&self, name: &str) -> Option<&lykoi_gl::UniformTypes> {
+	pub fn get_uniform(&self, name: &str) -> Option<&ShaderUniform> {
 		self.uniforms.get(name)
 	}
+	pub fn get_block(&self, name: &str) -> Option<&ShaderBlock> {
+		self.blocks.get(name)
+	}
+	pub fn get_storage(&self, name: &str) -> Option<&ShaderBlock> {
+		self.storage.get(name)
+	}
+	pub fn get_attribute(&self, name: &str) -> Option<&ShaderAttribute> {
+		self.attributes.get(name)
+	}
+	/// whether the shader has tessellation stages, and so draws patches.
+	pub fn has_tessellation(&self) -> bool {
+		self.tessellation
+	}
+
+	/// checks that `formats` together feed every attribute the shader consumes with fields that
+	/// fit it, and that named fields (see [`VertexFormat::named()`]) sit at the location of the
+	/// attribute with that name.
+	///
+	/// a field fits if it's read as integers exactly for integer attributes. integer attributes
+	/// also need as many components as the field and the same signedness, while float
+	/// attributes (or columns of them, for matrices) can have fewer, as the missing components
+	/// default to `0, 0, 1`.
+	///
+	/// this is done by every draw call, with the formats of the vertex and instance buffers.
+	pub fn check_attributes(&self, formats: &[&VertexFormat]) -> Result<(), Error> {
+		for (name, attribute) in &self.attributes {
+			let Some((columns, rows, integer)) = attribute.kind.attribute_shape() else {
+				continue;
+			};
+			let locations = columns as u32 * attribute.size as u32;
+			for location in attribute.location..attribute.location + locations {
+				let Some((kind, len, field_integer)) = formats.iter().find_map(|f| f.field_at(location)) else {
+					return Err(Error::AttributeMissing(name.clone()));
+				};
+				let fits = if integer {
+					field_integer && len == rows && kind.is_unsigned() == attribute.kind.is_unsigned()
+				} else {
+					!field_integer && len <= rows
+				};
+				if !fits {
+					return Err(Error::AttributeMismatch {
+						name: name.clone(),
+						expected: attribute.kind,
+					});
+				}
+			}
+		}
+
+		for (name, location) in formats.iter().flat_map(|f| f.names()) {
+			if let Some(attribute) = self.attributes.get(name) && attribute.location != location {
+				return Err(Error::AttributeLocation {
+					name: name.to_string(),
+					expected: attribute.location,
+					got: location,
+				});
+			}
+		}
+
+		Ok(())
+	}
+
+	/// checks `uniforms` against the shader's active uniforms, without drawing anything.
+	///
+	/// unlike a draw call, this doesn't stop at the first problem, and also reports
+	/// uniforms the list never sets. names given more than once are only checked once.
+	pub fn validate(&self, uniforms: &impl UniformList) -> UniformReport {
+		let mut report = UniformReport::default();
+		let mut seen = HashSet::new();
+
+		uniforms.visit(|name, value| {
+			if !seen.insert(name) {
+				return;
+			}
+			let blocks = match value {
+				UniformTypes::Block(_, size) => Some((&self.blocks, size)),
+				UniformTypes::Storage(_, size) => Some((&self.storage, size)),
+				_ => None,
+			};
+			if let Some((blocks, size)) = blocks {
+				match blocks.get(name) {
+					None => report.missing.push(name.to_string()),
+					Some(block) if size < block.size => {
+						report.undersized.push((name.to_string(), block.size));
+					},
+					Some(_) => (),
+				}
+				return;
+			}
+			match self.uniforms.get(name) {
+				None => report.missing.push(name.to_string()),
+				Some(uniform) if !value.fits(uniform.kind, uniform.size) => {
+					report.mismatched.push((name.to_string(), uniform.kind));
+				},
+				Some(_) => (),
+			}
+		});
+
+		report.unset = self.uniforms.keys()
+			.chain(self.blocks.keys())
+			.chain(self.storage.keys())
+			.filter(|name| !seen.contains(name.as_str()))
+			.cloned()
+			.collect();
+		report.unset.sort();
+
+		report
+	}
 }
-impl Drop for Shader {
-	fn drop(&mut self) {
-		lykoi_gl::delete_program(unsafe { std::ptr::read(&self.object) });
+impl Program for Shader {
+	fn bind(&self) {
+		lykoi_gl::use_program(self.handle());
+	}
+	fn vertex_stage(&self) -> &Shader {
+		self
+	}
+	fn visit_stages(&self, mut callback: impl FnMut(&Shader)) {
+		callback(self);
 	}
 }
-
@@ -1,6 +1,8 @@
 
 use std::cell::OnceCell;
 
+use super::{ext, Error, Owned, ReleaseQueue};
+
 #[derive(Debug, Clone, Copy)]
 pub enum TextureFormatWrap {
 	Repeat,
@@ -37,61 +39,83 @@ impl TextureFormat {
 		self
 	}
 }
+impl Default for TextureFormat {
+	fn default() -> Self {
+		Self::new()
+	}
+}
 
 
 #[derive(Debug)]
 pub struct Texture {
 	size: (usize, usize),
-	object: lykoi_gl::TextureObject,
+	format: lykoi_gl::TexImageInnerFormat,
+	object: Owned<lykoi_gl::TextureObject>,
 	surface: OnceCell<Surface>,
 }
 impl Texture {
+	/// creates a texture from rgba8 `data`.
+	///
+	/// panics if `data` isn't `width * height * 4` bytes long, see [`Self::try_new()`].
 	pub fn new(width: usize, height: usize, data: &[u8]) -> Self {
-		if data.len() != (width * height * 4) as usize {
-			panic!("expected size {}, got {}", data.len(), width * height * 4);
+		Self::try_new(width, height, data).unwrap_or_else(|e| panic!("{}", e))
+	}
+	pub fn try_new(width: usize, height: usize, data: &[u8]) -> Result<Self, Error> {
+		if data.len() != width * height * 4 {
+			return Err(Error::SizeMismatch {
+				expected: width * height * 4,
+				got: data.len(),
+			});
 		}
+		Self::create(width, height, Some(data))
+	}
+	/// creates a texture with undefined contents, ie. to draw onto.
+	///
+	/// panics if it's bigger than the context allows, see [`Self::try_new_empty()`].
+	pub fn new_empty(width: usize, height: usize) -> Self {
+		Self::try_new_empty(width, height).unwrap_or_else(|e| panic!("{}", e))
+	}
+	pub fn try_new_empty(width: usize, height: usize) -> Result<Self, Error> {
+		Self::create(width, height, None)
+	}
 
-		let [object] = lykoi_gl::gen_textures();
+	fn create(width: usize, height: usize, data: Option<&[u8]>) -> Result<Self, Error> {
+		let max = ext::get_integer_v(gl::MAX_TEXTURE_SIZE) as usize;
+		if width > max || height > max {
+			return Err(Error::TextureSize { width, height, max });
+		}
 
-		lykoi_gl::bind_texture(lykoi_gl::BindTextureTarget::Texture2D, &object);
-		lykoi_gl::tex_image_2d(
-			lykoi_gl::TexImage2DTarget::Texture2D,
-			0,
-			lykoi_gl::TexImageInnerFormat::RGBA,
-			width,
-			height,
-			lykoi_gl::TexImageDataFormat::RGBA,
-			lykoi_gl::TexImageDataType::UnsignedByte,
-			Some(data),
-		);
-		lykoi_gl::unbind_texture(lykoi_gl::BindTextureTarget::Texture2D);
+		// a sized format, since image units can't use textures with an unsized one.
+		let format = lykoi_gl::TexImageInnerFormat::RGBA8;
 
-		Self {
-			object,
-			size: (width, height),
-			surface: OnceCell::new(),
-		}
-	}
-	pub fn new_empty(width: usize, height: usize) -> Self {
 		let [object] = lykoi_gl::gen_textures();
 
 		lykoi_gl::bind_texture(lykoi_gl::BindTextureTarget::Texture2D, &object);
 		lykoi_gl::tex_image_2d(
 			lykoi_gl::TexImage2DTarget::Texture2D,
 			0,
-			lykoi_gl::TexImageInnerFormat::RGBA,
+			format,
 			width,
 			height,
 			lykoi_gl::TexImageDataFormat::RGBA,
 			lykoi_gl::TexImageDataType::UnsignedByte,
-			None,
+			data,
 		);
 		lykoi_gl::unbind_texture(lykoi_gl::BindTextureTarget::Texture2D);
 
-		Self {
-			object,
+		Ok(Self {
+			object: Owned::new(object),
 			size: (width, height),
+			format,
 			surface: OnceCell::new(),
+		})
+	}
+
+	/// defer deleting the texture, and its surface, to `queue`. see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.object.release_into(queue);
+		if let Some(surf) = self.surface.get_mut() {
+			surf.release_into(queue);
 		}
 	}
 
@@ -104,14 +128,27 @@ impl Texture {
 	pub fn height(&self) -> usize {
 		self.size.1
 	}
+	/// the internal format the texture was allocated with.
+	pub fn format(&self) -> lykoi_gl::TexImageInnerFormat {
+		self.format
+	}
 
+	/// the surface to draw onto this texture with, created on first use.
+	///
+	/// panics if the framebuffer isn't complete, see [`Self::try_surface()`].
 	pub fn surface(&self) -> &Surface {
+		self.try_surface().unwrap_or_else(|e| panic!("{}", e))
+	}
+	pub fn try_surface(&self) -> Result<&Surface, Error> {
 		match self.surface.get() {
-			Some(v) => v,
+			Some(v) => Ok(v),
 			None => {
-				let surf = Surface::new(self);
+				let mut surf = Surface::new(self)?;
+				if let Some(queue) = self.object.queue() {
+					surf.release_into(queue);
+				}
 				self.surface.set(surf).unwrap();
-				self.surface.get().unwrap()
+				Ok(self.surface.get().unwrap())
 			},
 		}
 	}
@@ -119,12 +156,12 @@ impl Texture {
 
 #[derive(Debug)]
 pub struct Surface {
-	object_framebuffer: lykoi_gl::FramebufferObject,
-	object_renderbuffer: lykoi_gl::RenderbufferObject,
+	object_framebuffer: Owned<lykoi_gl::FramebufferObject>,
+	object_renderbuffer: Owned<lykoi_gl::RenderbufferObject>,
 	size: (usize, usize),
 }
 impl Surface {
-	fn new(target: &Texture) -> Self {
+	fn new(target: &Texture) -> Result<Self, Error> {
 		let [object_framebuffer] = lykoi_gl::gen_framebuffers();
 		lykoi_gl::bind_framebuffer(lykoi_gl::FramebufferTarget::Framebuffer, &object_framebuffer);
 
@@ -146,17 +183,24 @@ impl Surface {
 			&object_renderbuffer
 		);
 
-		if !lykoi_gl::check_framebuffer_status(lykoi_gl::FramebufferTarget::Framebuffer) {
-			panic!("oops");
-		}
+		let status = ext::check_framebuffer_status(lykoi_gl::FramebufferTarget::Framebuffer);
 
 		lykoi_gl::unbind_framebuffer(lykoi_gl::FramebufferTarget::Framebuffer);
 
-		Self {
-			object_framebuffer,
-			object_renderbuffer,
-			size: target.size,
+		if status != gl::FRAMEBUFFER_COMPLETE {
+			return Err(Error::FramebufferIncomplete(status));
 		}
+
+		Ok(Self {
+			object_framebuffer: Owned::new(object_framebuffer),
+			object_renderbuffer: Owned::new(object_renderbuffer),
+			size: target.size,
+		})
+	}
+
+	fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.object_framebuffer.release_into(queue);
+		self.object_renderbuffer.release_into(queue);
 	}
 
 	pub fn handle_framebuffer(&self) -> &lykoi_gl::FramebufferObject {
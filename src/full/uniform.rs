@@ -1,14 +1,98 @@
 
-use super::{Texture, TextureFormat};
+use super::color::Color;
+use super::{GlslType, ImageAccess, Texture, TextureFormat};
 
 
+/**
+a value to set a uniform to.
+
+matrices are column major, ie. `Mat2x3` is 2 columns of 3 rows, matching glsl's `mat2x3`.
+the `*Array` variants set uniform arrays, starting at the first element.
+`Block` binds a buffer of the given size in bytes to a uniform block, see [`crate::UniformBuffer`],
+and `Storage` to a shader storage block, see [`crate::StorageBuffer`].
+*/
+#[derive(Clone, Copy)]
 pub enum UniformTypes<'a> {
 	Float1(f32),
 	Float2([f32; 2]),
 	Float3([f32; 3]),
 	Float4([f32; 4]),
+	Int1(i32),
+	Int2([i32; 2]),
+	Int3([i32; 3]),
+	Int4([i32; 4]),
+	UInt1(u32),
+	UInt2([u32; 2]),
+	UInt3([u32; 3]),
+	UInt4([u32; 4]),
+	Bool1(bool),
+	Bool2([bool; 2]),
+	Bool3([bool; 3]),
+	Bool4([bool; 4]),
+	Mat2x2([[f32; 2]; 2]),
+	Mat3x3([[f32; 3]; 3]),
 	Mat4x4(&'a cgmath::Matrix4<f32>),
+	Mat2x3([[f32; 3]; 2]),
+	Mat2x4([[f32; 4]; 2]),
+	Mat3x2([[f32; 2]; 3]),
+	Mat3x4([[f32; 4]; 3]),
+	Mat4x2([[f32; 2]; 4]),
+	Mat4x3([[f32; 3]; 4]),
+	FloatArray(&'a [f32]),
+	Float2Array(&'a [[f32; 2]]),
+	Float3Array(&'a [[f32; 3]]),
+	Float4Array(&'a [[f32; 4]]),
+	IntArray(&'a [i32]),
+	UIntArray(&'a [u32]),
+	Mat4x4Array(&'a [cgmath::Matrix4<f32>]),
 	Sampler2D(&'a Texture, &'a TextureFormat),
+	Image(&'a Texture, ImageAccess),
+	Block(&'a lykoi_gl::BufferObject, usize),
+	Storage(&'a lykoi_gl::BufferObject, usize),
+}
+impl UniformTypes<'_> {
+	/// whether this value can be given to a uniform of type `kind` with `size` array elements.
+	/// always `false` for `Block` and `Storage`, which aren't set through a uniform.
+	pub fn fits(&self, kind: GlslType, size: usize) -> bool {
+		let (expected, len) = match self {
+			UniformTypes::Float1(_) => (GlslType::Float, 1),
+			UniformTypes::Float2(_) => (GlslType::Vec2, 1),
+			UniformTypes::Float3(_) => (GlslType::Vec3, 1),
+			UniformTypes::Float4(_) => (GlslType::Vec4, 1),
+			UniformTypes::Int1(_) => (GlslType::Int, 1),
+			UniformTypes::Int2(_) => (GlslType::IVec2, 1),
+			UniformTypes::Int3(_) => (GlslType::IVec3, 1),
+			UniformTypes::Int4(_) => (GlslType::IVec4, 1),
+			UniformTypes::UInt1(_) => (GlslType::UInt, 1),
+			UniformTypes::UInt2(_) => (GlslType::UVec2, 1),
+			UniformTypes::UInt3(_) => (GlslType::UVec3, 1),
+			UniformTypes::UInt4(_) => (GlslType::UVec4, 1),
+			UniformTypes::Bool1(_) => (GlslType::Bool, 1),
+			UniformTypes::Bool2(_) => (GlslType::BVec2, 1),
+			UniformTypes::Bool3(_) => (GlslType::BVec3, 1),
+			UniformTypes::Bool4(_) => (GlslType::BVec4, 1),
+			UniformTypes::Mat2x2(_) => (GlslType::Mat2, 1),
+			UniformTypes::Mat3x3(_) => (GlslType::Mat3, 1),
+			UniformTypes::Mat4x4(_) => (GlslType::Mat4, 1),
+			UniformTypes::Mat2x3(_) => (GlslType::Mat2x3, 1),
+			UniformTypes::Mat2x4(_) => (GlslType::Mat2x4, 1),
+			UniformTypes::Mat3x2(_) => (GlslType::Mat3x2, 1),
+			UniformTypes::Mat3x4(_) => (GlslType::Mat3x4, 1),
+			UniformTypes::Mat4x2(_) => (GlslType::Mat4x2, 1),
+			UniformTypes::Mat4x3(_) => (GlslType::Mat4x3, 1),
+			UniformTypes::FloatArray(v) => (GlslType::Float, v.len()),
+			UniformTypes::Float2Array(v) => (GlslType::Vec2, v.len()),
+			UniformTypes::Float3Array(v) => (GlslType::Vec3, v.len()),
+			UniformTypes::Float4Array(v) => (GlslType::Vec4, v.len()),
+			UniformTypes::IntArray(v) => (GlslType::Int, v.len()),
+			UniformTypes::UIntArray(v) => (GlslType::UInt, v.len()),
+			UniformTypes::Mat4x4Array(v) => (GlslType::Mat4, v.len()),
+			UniformTypes::Sampler2D(..) => (GlslType::Sampler2D, 1),
+			UniformTypes::Image(..) => (GlslType::Image2D, 1),
+			UniformTypes::Block(..) | UniformTypes::Storage(..) => return false,
+		};
+		expected == kind && len <= size
+	}
 }
 
 pub trait AsUniformType {
@@ -45,6 +129,161 @@ impl AsUniformType for [f32; 4] {
 		UniformTypes::Float4(*self)
 	}
 }
+impl AsUniformType for i32 {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Int1(*self)
+	}
+}
+impl AsUniformType for [i32; 1] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Int1(self[0])
+	}
+}
+impl AsUniformType for [i32; 2] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Int2(*self)
+	}
+}
+impl AsUniformType for [i32; 3] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Int3(*self)
+	}
+}
+impl AsUniformType for [i32; 4] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Int4(*self)
+	}
+}
+impl AsUniformType for u32 {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::UInt1(*self)
+	}
+}
+impl AsUniformType for [u32; 1] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::UInt1(self[0])
+	}
+}
+impl AsUniformType for [u32; 2] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::UInt2(*self)
+	}
+}
+impl AsUniformType for [u32; 3] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::UInt3(*self)
+	}
+}
+impl AsUniformType for [u32; 4] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::UInt4(*self)
+	}
+}
+impl AsUniformType for bool {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Bool1(*self)
+	}
+}
+impl AsUniformType for [bool; 1] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Bool1(self[0])
+	}
+}
+impl AsUniformType for [bool; 2] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Bool2(*self)
+	}
+}
+impl AsUniformType for [bool; 3] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Bool3(*self)
+	}
+}
+impl AsUniformType for [bool; 4] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Bool4(*self)
+	}
+}
+impl AsUniformType for [[f32; 2]; 2] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat2x2(*self)
+	}
+}
+impl AsUniformType for [[f32; 3]; 3] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat3x3(*self)
+	}
+}
+impl AsUniformType for [[f32; 4]; 4] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat4x4(self.into())
+	}
+}
+impl AsUniformType for [[f32; 3]; 2] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat2x3(*self)
+	}
+}
+impl AsUniformType for [[f32; 4]; 2] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat2x4(*self)
+	}
+}
+impl AsUniformType for [[f32; 2]; 3] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat3x2(*self)
+	}
+}
+impl AsUniformType for [[f32; 4]; 3] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat3x4(*self)
+	}
+}
+impl AsUniformType for [[f32; 2]; 4] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat4x2(*self)
+	}
+}
+impl AsUniformType for [[f32; 3]; 4] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat4x3(*self)
+	}
+}
+impl AsUniformType for &[f32] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::FloatArray(self)
+	}
+}
+impl AsUniformType for &[[f32; 2]] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float2Array(self)
+	}
+}
+impl AsUniformType for &[[f32; 3]] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float3Array(self)
+	}
+}
+impl AsUniformType for &[[f32; 4]] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float4Array(self)
+	}
+}
+impl AsUniformType for &[i32] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::IntArray(self)
+	}
+}
+impl AsUniformType for &[u32] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::UIntArray(self)
+	}
+}
+impl AsUniformType for &[cgmath::Matrix4<f32>] {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat4x4Array(self)
+	}
+}
 impl AsUniformType for (&Texture, &TextureFormat) {
 	fn convert(&'_ self) -> UniformTypes<'_> {
 		UniformTypes::Sampler2D(self.0, self.1)
@@ -55,10 +294,114 @@ impl AsUniformType for &cgmath::Matrix4<f32> {
 		UniformTypes::Mat4x4(self)
 	}
 }
+impl AsUniformType for cgmath::Matrix4<f32> {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat4x4(self)
+	}
+}
+impl AsUniformType for cgmath::Matrix3<f32> {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat3x3((*self).into())
+	}
+}
+impl AsUniformType for cgmath::Matrix2<f32> {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Mat2x2((*self).into())
+	}
+}
+impl AsUniformType for cgmath::Vector2<f32> {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float2((*self).into())
+	}
+}
+impl AsUniformType for cgmath::Vector3<f32> {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float3((*self).into())
+	}
+}
+impl AsUniformType for cgmath::Vector4<f32> {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float4((*self).into())
+	}
+}
+impl AsUniformType for cgmath::Point2<f32> {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float2((*self).into())
+	}
+}
+impl AsUniformType for cgmath::Point3<f32> {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float3((*self).into())
+	}
+}
+/// as a `vec4` of `(x, y, z, w)`, with `w` being the scalar part.
+impl AsUniformType for cgmath::Quaternion<f32> {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float4([self.v.x, self.v.y, self.v.z, self.s])
+	}
+}
+/// as a `vec4`, use [`Color::rgb()`] for a `vec3`.
+impl AsUniformType for Color {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float4(self.rgba())
+	}
+}
+impl AsUniformType for (f32, f32) {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float2([self.0, self.1])
+	}
+}
+impl AsUniformType for (f32, f32, f32) {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float3([self.0, self.1, self.2])
+	}
+}
+impl AsUniformType for (f32, f32, f32, f32) {
+	fn convert(&'_ self) -> UniformTypes<'_> {
+		UniformTypes::Float4([self.0, self.1, self.2, self.3])
+	}
+}
+
+/**
+a set of named uniform values, given to a draw call.
+
+[`UniformItem`] builds one up by hand. with the `derive` feature, `Uniforms` can be derived
+for a struct instead, visiting each field under its name:
+
+```
+# #[cfg(feature = "derive")] {
+use lykoi_drive::{UniformList, Uniforms};
+
+#[derive(Uniforms)]
+struct Globals {
+    #[uniform(name = "u_time")]
+    time: f32,
+    u_tint: [f32; 4],
+    #[uniform(skip)]
+    frame: usize,
+}
+
+let globals = Globals { time: 1.0, u_tint: [1.0; 4], frame: 0 };
+
+let mut names = Vec::new();
+globals.visit(|name, _| names.push(name));
+assert_eq!(names, ["u_time", "u_tint"]);
+# }
+```
 
+- `#[uniform(name = "...")]` sets the name a field is set under, which defaults to the field's name.
+- `#[uniform(skip)]` leaves a field out.
+
+every field that isn't skipped must be [`AsUniformType`].
+*/
 pub trait UniformList {
 	fn visit(&self, callback: impl FnMut(&'static str, UniformTypes));
 }
+impl<T: UniformList> UniformList for &T {
+	fn visit(&self, callback: impl FnMut(&'static str, UniformTypes)) {
+		(*self).visit(callback);
+	}
+}
 
 pub struct UniformEmpty;
 impl UniformList for UniformEmpty {
@@ -92,6 +435,11 @@ impl UniformItem<(), UniformEmpty> {
 		}
 	}
 }
+impl Default for UniformItem<(), UniformEmpty> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
 
 impl<T, N> UniformItem<T, N>
 where T: AsUniformType, N: UniformList {
@@ -105,4 +453,3 @@ where T: AsUniformType, N: UniformList {
 		}
 	}
 }
-
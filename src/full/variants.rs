@@ -0,0 +1,172 @@
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+
+use super::{Error, Preprocessor, ProgramCache, ReleaseQueue, Shader, ShaderPartFragment, ShaderPartVertex, ShaderSource};
+
+
+/**
+a combination of features to build a variant of a shader with, see [`ShaderVariants`].
+
+each feature becomes a `#define` in both stages. flags that are off aren't defined at all, so
+shaders test them with `#ifdef`, and a key with a flag turned off is the same key as one that
+never mentions it.
+
+```
+use lykoi_drive::ShaderKey;
+
+let a = ShaderKey::new().flag("FOG", true).flag("ALPHA_TEST", false).value("TEXTURES", 2);
+let b = ShaderKey::new().value("TEXTURES", 2).flag("FOG", true);
+
+assert_eq!(a, b);
+assert_ne!(a, ShaderKey::new().value("TEXTURES", 3).flag("FOG", true));
+```
+*/
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ShaderKey {
+	defines: BTreeMap<String, String>,
+}
+impl ShaderKey {
+	pub fn new() -> Self {
+		Self {
+			defines: BTreeMap::new(),
+		}
+	}
+
+	/// defines `name` as `1` if `on`, and leaves it undefined otherwise.
+	pub fn flag(mut self, name: &str, on: bool) -> Self {
+		if on {
+			self.defines.insert(name.to_string(), "1".to_string());
+		} else {
+			self.defines.remove(name);
+		}
+		self
+	}
+
+	/// defines `name` as `value`, for features with more than two states, ie. a texture count
+	/// or a fog mode.
+	pub fn value(mut self, name: &str, value: impl Display) -> Self {
+		self.defines.insert(name.to_string(), value.to_string());
+		self
+	}
+
+	fn defines(&self) -> Vec<(String, String)> {
+		self.defines.iter()
+			.map(|(name, value)| (name.clone(), value.clone()))
+			.collect()
+	}
+}
+
+
+/**
+every variant of a shader that differs only by its features, ie. fog on or off.
+
+variants are compiled by [`Self::prepare()`], with the defines of their [`ShaderKey`] injected
+after `#version`, and kept around for later draws. [`Self::get()`] only borrows the variants
+immutably, so any amount of them can be used at once.
+
+```ignore
+let mut sprite = ShaderVariants::new(SPRITE_VERT, SPRITE_FRAG);
+
+let fog = ShaderKey::new().flag("FOG", true).value("TEXTURES", 2);
+let plain = ShaderKey::new().value("TEXTURES", 2);
+sprite.prepare([&fog, &plain])?;
+
+let (fog, plain) = (sprite.get(&fog).unwrap(), sprite.get(&plain).unwrap());
+draw.draw(None, &level, fog, uniforms, Config::new());
+draw.draw(None, &hud, plain, uniforms, Config::new());
+```
+
+failing variants aren't kept, so they're compiled again on the next [`Self::prepare()`].
+*/
+pub struct ShaderVariants<S: ShaderSource> {
+	preprocessor: Preprocessor<S>,
+	vertex: String,
+	fragment: String,
+	cache: Option<ProgramCache>,
+	shaders: HashMap<ShaderKey, Shader>,
+	queue: Option<ReleaseQueue>,
+}
+impl ShaderVariants<HashMap<String, String>> {
+	/// variants of the shader with the sources `vertex_src` and `fragment_src`. these can't
+	/// `#include` anything, see [`Self::from_preprocessor()`] for that.
+	pub fn new(vertex_src: &str, fragment_src: &str) -> Self {
+		let mut files = HashMap::new();
+		files.insert("main.vert".to_string(), vertex_src.to_string());
+		files.insert("main.frag".to_string(), fragment_src.to_string());
+
+		Self::from_preprocessor(Preprocessor::new(files), "main.vert", "main.frag")
+	}
+}
+impl<S: ShaderSource> ShaderVariants<S> {
+	/// variants of the shader made from the files at `vertex` and `fragment`. defines of the
+	/// preprocessor are shared by every variant.
+	pub fn from_preprocessor(preprocessor: Preprocessor<S>, vertex: &str, fragment: &str) -> Self {
+		Self {
+			preprocessor,
+			vertex: vertex.to_string(),
+			fragment: fragment.to_string(),
+			cache: None,
+			shaders: HashMap::new(),
+			queue: None,
+		}
+	}
+
+	/// restore variants from `cache` instead of compiling them, see [`ProgramCache`].
+	pub fn cache(mut self, cache: ProgramCache) -> Self {
+		self.cache = Some(cache);
+		self
+	}
+
+	/// compiles the variants for `keys` that haven't been compiled yet. stops at the first one
+	/// that fails, keeping the ones compiled before it.
+	pub fn prepare<'k>(&mut self, keys: impl IntoIterator<Item = &'k ShaderKey>) -> Result<(), Error> {
+		for key in keys {
+			if self.shaders.contains_key(key) {
+				continue;
+			}
+			let mut shader = self.build(key)?;
+			if let Some(ref queue) = self.queue {
+				shader.release_into(queue);
+			}
+			self.shaders.insert(key.clone(), shader);
+		}
+		Ok(())
+	}
+
+	/// the variant for `key`, or `None` if it hasn't been compiled by [`Self::prepare()`].
+	pub fn get(&self, key: &ShaderKey) -> Option<&Shader> {
+		self.shaders.get(key)
+	}
+
+	fn build(&self, key: &ShaderKey) -> Result<Shader, Error> {
+		let defines = key.defines();
+		let vertex = self.preprocessor.process_with(&self.vertex, &defines)?;
+		let fragment = self.preprocessor.process_with(&self.fragment, &defines)?;
+
+		match self.cache {
+			Some(ref cache) => cache.shader_preprocessed(&vertex, &fragment),
+			None => Shader::new(
+				ShaderPartVertex::new_preprocessed(&vertex)?,
+				ShaderPartFragment::new_preprocessed(&fragment)?,
+			),
+		}
+	}
+
+	/// the amount of variants compiled so far.
+	pub fn len(&self) -> usize {
+		self.shaders.len()
+	}
+	pub fn is_empty(&self) -> bool {
+		self.shaders.is_empty()
+	}
+
+	/// defer deleting every variant, including ones compiled later, to `queue`.
+	/// see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		for shader in self.shaders.values_mut() {
+			shader.release_into(queue);
+		}
+		self.queue = Some(queue.clone());
+	}
+}
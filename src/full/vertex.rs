@@ -1,14 +1,75 @@
 
+use super::{ext, Error, Owned, ReleaseQueue};
+
+
+/// how often the contents of a [`VertexBuffer`] are expected to change.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VertexBufferUsage {
+	/// uploaded once, drawn many times.
+	Static,
+	/// changed every now and then, drawn many times.
+	Dynamic,
+	/// changed about every time it's drawn, ie. per frame geometry.
+	Stream,
+}
+impl VertexBufferUsage {
+	fn to(&self) -> lykoi_gl::BufferDataUsage {
+		match self {
+			VertexBufferUsage::Static => lykoi_gl::BufferDataUsage::StaticDraw,
+			VertexBufferUsage::Dynamic => lykoi_gl::BufferDataUsage::DynamicDraw,
+			VertexBufferUsage::Stream => lykoi_gl::BufferDataUsage::StreamDraw,
+		}
+	}
+}
+
 pub struct VertexBuffer<T> {
-	prepared: bool,
-	object_buffer: lykoi_gl::BufferObject,
-	object_array: lykoi_gl::VertexArrayObject,
+	object_buffer: Owned<lykoi_gl::BufferObject>,
+	object_array: Owned<lykoi_gl::VertexArrayObject>,
 	data: Option<Vec<T>>,
+	len: usize,
+	capacity: usize,
+	usage: VertexBufferUsage,
 	format: VertexFormat,
 }
 impl<T> VertexBuffer<T> {
-	pub fn new(format: &VertexFormat) -> Self {
-		let [object_buffer] = lykoi_gl::gen_buffers();
+	pub fn handle_buffer(&self) -> &lykoi_gl::BufferObject {
+		&self.object_buffer
+	}
+	pub fn handle_array(&self) -> &lykoi_gl::VertexArrayObject {
+		&self.object_array
+	}
+	/// defer deleting the buffer and vao to `queue`, see [`ReleaseQueue`].
+	pub fn release_into(&mut self, queue: &ReleaseQueue) {
+		self.object_buffer.release_into(queue);
+		self.object_array.release_into(queue);
+	}
+	pub fn get_format(&self) -> &VertexFormat {
+		&self.format
+	}
+	pub fn get_usage(&self) -> VertexBufferUsage {
+		self.usage
+	}
+	pub fn get_verts_len(&self) -> usize {
+		self.len
+	}
+	/// amount of vertices that fit in the buffer's current storage.
+	pub fn get_capacity(&self) -> usize {
+		self.capacity
+	}
+	/// the cpu side copy of the vertices, unless disabled with [`Self::keep_data()`].
+	pub fn get_data(&self) -> Option<&[T]> {
+		self.data.as_deref()
+	}
+}
+
+impl<T: Pod> VertexBuffer<T> {
+	/// creates an empty vertex buffer.
+	///
+	/// fails if the stride of `format` doesn't match the size of `T`.
+	pub fn new(format: &VertexFormat) -> Result<Self, Error> {
+		Self::check_format(format)?;
+
+		let object_buffer = ext::gen_buffer().ok_or(Error::Create("buffer"))?;
 		let [object_array] = lykoi_gl::gen_vertex_arrays();
 
 		lykoi_gl::bind_vertex_array(&object_array);
@@ -18,61 +79,267 @@ impl<T> VertexBuffer<T> {
 
 		lykoi_gl::unbind_vertex_array();
 
-		Self {
-			prepared: false,
-			object_buffer,
-			object_array,
-			data: None,
+		Ok(Self {
+			object_buffer: Owned::new(object_buffer),
+			object_array: Owned::new(object_array),
+			data: Some(Vec::new()),
+			len: 0,
+			capacity: 0,
+			usage: VertexBufferUsage::Static,
 			format: format.clone(),
+		})
+	}
+
+	pub fn new_from(data: &[T], format: &VertexFormat) -> Result<Self, Error> {
+		let mut s = Self::new(format)?;
+		s.rebuffer(data)?;
+		Ok(s)
+	}
+
+	/// sets the usage hint used for future allocations of the buffer's storage.
+	///
+	/// non [`VertexBufferUsage::Static`] buffers grow their storage in powers of two, so
+	/// [`Self::rebuffer()`] with a similar amount of vertices doesn't reallocate.
+	pub fn usage(mut self, usage: VertexBufferUsage) -> Self {
+		self.usage = usage;
+		self
+	}
+
+	/// whether to keep a cpu side copy of the vertices. if not, only the amount of vertices is tracked.
+	///
+	/// turning it on for a buffer that already has vertices reads them back from the gpu.
+	pub fn keep_data(mut self, keep: bool) -> Self {
+		if !keep {
+			self.data = None;
+		} else if self.data.is_none() {
+			self.data = Some(self.read_back());
 		}
+		self
 	}
 
-	pub fn handle_buffer(&self) -> &lykoi_gl::BufferObject {
-		&self.object_buffer
+	/// makes sure the buffer's storage fits at least `capacity` vertices, keeping its contents.
+	pub fn reserve(&mut self, capacity: usize) {
+		if capacity <= self.capacity {
+			return;
+		}
+
+		// reallocating drops the old contents, so they're uploaded again afterwards.
+		let old = match self.data {
+			Some(_) => None,
+			None => Some(self.read_back()),
+		};
+
+		self.allocate(capacity);
+
+		let old = old.as_deref().or(self.data.as_deref()).unwrap_or_default();
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer, &self.object_buffer);
+		ext::buffer_sub_data(lykoi_gl::BindBufferTarget::ArrayBuffer, 0, old);
+		lykoi_gl::unbind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer);
 	}
-	pub fn handle_array(&self) -> &lykoi_gl::VertexArrayObject {
-		&self.object_array
+
+	/// reallocates the buffer's storage to fit at least `capacity` vertices, dropping its contents
+	/// on the gpu side.
+	fn allocate(&mut self, capacity: usize) {
+		let capacity = match self.usage {
+			VertexBufferUsage::Static => capacity,
+			_ => capacity.max(self.capacity * 2),
+		};
+
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer, &self.object_buffer);
+		ext::buffer_data_empty(lykoi_gl::BindBufferTarget::ArrayBuffer, capacity * size_of::<T>(), self.usage.to());
+		lykoi_gl::unbind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer);
+
+		self.capacity = capacity;
 	}
-	pub fn get_verts_len(&self) -> usize {
-		if let Some(ref data) = self.data {
-			data.len()
-		} else {
-			0
+
+	/// reads the vertices back from the gpu.
+	fn read_back(&self) -> Vec<T> {
+		if self.len == 0 {
+			return Vec::new();
 		}
+		// safety: T is Pod, so all zeroes is a valid T.
+		let mut out = vec![unsafe { std::mem::zeroed::<T>() }; self.len];
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer, &self.object_buffer);
+		ext::get_buffer_sub_data(lykoi_gl::BindBufferTarget::ArrayBuffer, 0, &mut out);
+		lykoi_gl::unbind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer);
+		out
 	}
-}
 
-impl<T: Clone> VertexBuffer<T> {
-	pub fn new_from(data: &[T], format: &VertexFormat) -> Self {
-		let mut s = Self::new(format);
-		s.rebuffer(data);
-		return s;
+	/// replaces the contents of the buffer, reusing its storage if `data` fits.
+	pub fn rebuffer(&mut self, data: &[T]) -> Result<(), Error> {
+		Self::check_format(&self.format)?;
+
+		if data.len() > self.capacity {
+			self.allocate(data.len());
+		} else if self.usage == VertexBufferUsage::Stream {
+			// orphan the old storage, so the driver doesn't wait for draws still using it.
+			lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer, &self.object_buffer);
+			ext::buffer_data_empty(lykoi_gl::BindBufferTarget::ArrayBuffer, self.capacity * size_of::<T>(), self.usage.to());
+		}
+
+		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer, &self.object_buffer);
+		ext::buffer_sub_data(lykoi_gl::BindBufferTarget::ArrayBuffer, 0, data);
+		lykoi_gl::unbind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer);
+
+		self.len = data.len();
+		if let Some(ref mut copy) = self.data {
+			copy.clear();
+			copy.extend_from_slice(data);
+		}
+		Ok(())
 	}
 
-	pub fn rebuffer(&mut self, data: &[T]) {
-		lykoi_gl::bind_vertex_array(&self.object_array);
+	/// overwrites the vertices starting at `offset` with `data`, via `glBufferSubData`.
+	///
+	/// fails if the range reaches past the current amount of vertices.
+	pub fn update_range(&mut self, offset: usize, data: &[T]) -> Result<(), Error> {
+		if offset + data.len() > self.len {
+			return Err(Error::RangeOutOfBounds {
+				start: offset,
+				end: offset + data.len(),
+				len: self.len,
+			});
+		}
 
 		lykoi_gl::bind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer, &self.object_buffer);
-		lykoi_gl::buffer_data(lykoi_gl::BindBufferTarget::ArrayBuffer, &data, lykoi_gl::BufferDataUsage::StaticDraw);
+		ext::buffer_sub_data(lykoi_gl::BindBufferTarget::ArrayBuffer, offset * size_of::<T>(), data);
+		lykoi_gl::unbind_buffer(lykoi_gl::BindBufferTarget::ArrayBuffer);
 
-		lykoi_gl::unbind_vertex_array();
-		
-		self.prepared = true;
-		self.data = Some(data.to_vec());
+		if let Some(ref mut copy) = self.data {
+			copy[offset..offset + data.len()].copy_from_slice(data);
+		}
+		Ok(())
+	}
+
+	fn check_format(format: &VertexFormat) -> Result<(), Error> {
+		if format.get_stride() as usize != size_of::<T>() {
+			return Err(Error::StrideMismatch {
+				stride: format.get_stride() as usize,
+				size: size_of::<T>(),
+			});
+		}
+		Ok(())
+	}
+}
+
+
+/**
+marker for "plain old data" types, which can be uploaded to the gpu byte for byte.
+
+# Safety
+
+implementors must be `Copy`, contain no pointers or references, and have no padding bytes.
+`#[derive(VertexLayout)]` implements this for structs whose fields are all `Pod` and
+which have no padding.
+*/
+pub unsafe trait Pod: Copy + 'static {}
+
+unsafe impl Pod for f32 {}
+unsafe impl Pod for f64 {}
+unsafe impl Pod for i8 {}
+unsafe impl Pod for u8 {}
+unsafe impl Pod for i16 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for i32 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for i64 {}
+unsafe impl Pod for u64 {}
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+unsafe impl<T: Pod> Pod for cgmath::Vector2<T> {}
+unsafe impl<T: Pod> Pod for cgmath::Vector3<T> {}
+unsafe impl<T: Pod> Pod for cgmath::Vector4<T> {}
+unsafe impl<T: Pod> Pod for cgmath::Point2<T> {}
+unsafe impl<T: Pod> Pod for cgmath::Point3<T> {}
+unsafe impl<T: Pod> Pod for cgmath::Matrix2<T> {}
+unsafe impl<T: Pod> Pod for cgmath::Matrix3<T> {}
+unsafe impl<T: Pod> Pod for cgmath::Matrix4<T> {}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VertexFieldType {
+	Float,
+	/// 16 bit float. rust has no stable `f16`, so store the bits in a `u16`.
+	Half,
+	Byte,
+	UnsignedByte,
+	Short,
+	UnsignedShort,
+	Int,
+	UnsignedInt,
+}
+impl VertexFieldType {
+	/// size of one component, in bytes.
+	pub fn size(&self) -> u8 {
+		match self {
+			VertexFieldType::Float => 4,
+			VertexFieldType::Half => 2,
+			VertexFieldType::Byte => 1,
+			VertexFieldType::UnsignedByte => 1,
+			VertexFieldType::Short => 2,
+			VertexFieldType::UnsignedShort => 2,
+			VertexFieldType::Int => 4,
+			VertexFieldType::UnsignedInt => 4,
+		}
 	}
+
+	pub const fn is_integer(&self) -> bool {
+		!matches!(self, VertexFieldType::Float | VertexFieldType::Half)
+	}
+	pub const fn is_unsigned(&self) -> bool {
+		matches!(self, VertexFieldType::UnsignedByte | VertexFieldType::UnsignedShort | VertexFieldType::UnsignedInt)
+	}
+
+	fn to(&self) -> lykoi_gl::VertexAttribPointerType {
+		match self {
+			VertexFieldType::Float => lykoi_gl::VertexAttribPointerType::Float,
+			VertexFieldType::Half => lykoi_gl::VertexAttribPointerType::HalfFloat,
+			VertexFieldType::Byte => lykoi_gl::VertexAttribPointerType::Byte,
+			VertexFieldType::UnsignedByte => lykoi_gl::VertexAttribPointerType::UnsignedByte,
+			VertexFieldType::Short => lykoi_gl::VertexAttribPointerType::Short,
+			VertexFieldType::UnsignedShort => lykoi_gl::VertexAttribPointerType::UnsignedShort,
+			VertexFieldType::Int => lykoi_gl::VertexAttribPointerType::Int,
+			VertexFieldType::UnsignedInt => lykoi_gl::VertexAttribPointerType::UnsignedInt,
+		}
+	}
+}
+
+/// how a field's components are handed to the shader.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VertexFieldMode {
+	/// converted to float as is, ie. `255u8` becomes `255.0`.
+	Float,
+	/// integers mapped to `0.0..=1.0` (unsigned) or `-1.0..=1.0` (signed).
+	Normalized,
+	/// kept as integers, for `int`/`uint`/`ivecN`/`uvecN` inputs.
+	Integer,
 }
 
+#[derive(Debug, Clone)]
+struct VertexField {
+	len: u8,
+	kind: VertexFieldType,
+	mode: VertexFieldMode,
+	location: u32,
+	offset: u32,
+	name: Option<String>,
+}
 
 #[derive(Debug, Clone)]
 pub struct VertexFormat {
-	fields: Vec<(u8, u8)>,
+	fields: Vec<VertexField>,
 	stride: u32,
+	offset: u32,
+	location: u32,
+	divisor: u32,
 }
 impl VertexFormat {
 	pub fn new() -> Self {
 		Self {
 			fields: Vec::new(),
 			stride: 0,
+			offset: 0,
+			location: 0,
+			divisor: 0,
 		}
 	}
 
@@ -80,47 +347,249 @@ impl VertexFormat {
 		self
 	}
 
-	fn field(&mut self, len: u8) {
-		let size = size_of::<f32>();
-		self.stride += size as u32 * len as u32;
-		self.fields.push((len, size as u8));
+	/// attribute location of the next field. fields after it continue counting up from there.
+	///
+	/// this is mostly useful for per-instance formats, which have to sit after the locations
+	/// of the mesh they're drawn with.
+	pub fn location(mut self, location: u32) -> Self {
+		self.location = location;
+		self
+	}
+
+	/// byte offset of the next field. fields after it are packed right after it.
+	///
+	/// only needed if the vertex struct has padding between fields.
+	pub fn offset(mut self, offset: u32) -> Self {
+		self.offset = offset;
+		self
 	}
 
-	pub fn field_vec4(mut self) -> Self {
-		self.field(4);
+	/// overrides the size of one vertex, ie. to account for padding at the end of the vertex struct.
+	pub fn stride(mut self, stride: u32) -> Self {
+		self.stride = stride;
 		self
 	}
 
-	pub fn field_vec3(mut self) -> Self {
-		self.field(3);
+	/// advance the fields of this format once every `divisor` instances instead of once per
+	/// vertex. `0` (the default) means per vertex.
+	pub fn divisor(mut self, divisor: u32) -> Self {
+		self.divisor = divisor;
 		self
 	}
 
-	pub fn field_vec2(mut self) -> Self {
-		self.field(2);
+	/// adds a field of `len` components of `kind`.
+	///
+	/// panics if `len` isn't within `1..=4`, or if `mode` needs integer components and `kind`
+	/// isn't an integer type.
+	pub fn field_typed(mut self, kind: VertexFieldType, len: u8, mode: VertexFieldMode) -> Self {
+		assert!(matches!(len, 1..=4), "field length must be within 1..=4, got {}", len);
+		if mode != VertexFieldMode::Float && !kind.is_integer() {
+			panic!("{:?} fields can't use {:?}", kind, mode);
+		}
+
+		let offset = self.offset;
+		self.offset += kind.size() as u32 * len as u32;
+		self.stride = self.stride.max(self.offset);
+		self.fields.push(VertexField {
+			len,
+			kind,
+			mode,
+			location: self.location,
+			offset,
+			name: None,
+		});
+		self.location += 1;
 		self
 	}
 
-	pub fn field_float(mut self) -> Self {
-		self.field(1);
+	/// adds a field of `kind`, converted to float, ie. `i16` packed positions or half floats.
+	pub fn field_as(self, kind: VertexFieldType, len: u8) -> Self {
+		self.field_typed(kind, len, VertexFieldMode::Float)
+	}
+
+	/// adds a normalized integer field, ie. `u8` colors.
+	pub fn field_norm(self, kind: VertexFieldType, len: u8) -> Self {
+		self.field_typed(kind, len, VertexFieldMode::Normalized)
+	}
+
+	/// adds an integer field, ie. `u16` bone indices.
+	pub fn field_int(self, kind: VertexFieldType, len: u8) -> Self {
+		self.field_typed(kind, len, VertexFieldMode::Integer)
+	}
+
+	/// names the last added field after the shader attribute it feeds, so draws can check
+	/// the field sits at the same location as the attribute.
+	///
+	/// panics if no field was added yet.
+	pub fn named(mut self, name: &str) -> Self {
+		let field = self.fields.last_mut().expect("named() needs a field to name");
+		field.name = Some(name.to_string());
 		self
 	}
 
-	fn emit(&self) {
-		let mut offset = 0;
-		for i in 0..self.fields.len() {
-			let f = &self.fields[i];
-			lykoi_gl::vertex_attrib_pointer(
-				i as u32,
-				f.0,
-				lykoi_gl::VertexAttribPointerType::Float,
-				false,
-				self.stride,
-				offset,
-			);
-			lykoi_gl::enable_vertex_attrib_array(i as u32);
-			offset += f.0 as u32 * f.1 as u32;
+	pub fn field_vec4(self) -> Self {
+		self.field_as(VertexFieldType::Float, 4)
+	}
+
+	pub fn field_vec3(self) -> Self {
+		self.field_as(VertexFieldType::Float, 3)
+	}
+
+	pub fn field_vec2(self) -> Self {
+		self.field_as(VertexFieldType::Float, 2)
+	}
+
+	pub fn field_float(self) -> Self {
+		self.field_as(VertexFieldType::Float, 1)
+	}
+
+	/// adds a `[u8; 4]` field, normalized to a `vec4`.
+	pub fn field_color(self) -> Self {
+		self.field_norm(VertexFieldType::UnsignedByte, 4)
+	}
+
+	/// size of one vertex, in bytes.
+	pub fn get_stride(&self) -> u32 {
+		self.stride
+	}
+
+	pub fn get_divisor(&self) -> u32 {
+		self.divisor
+	}
+
+	pub(crate) fn locations(&self) -> impl Iterator<Item = u32> + '_ {
+		self.fields.iter().map(|f| f.location)
+	}
+
+	/// the type, component count and whether it's read as integers of the field at `location`,
+	/// or `None` if there's no field there.
+	pub(crate) fn field_at(&self, location: u32) -> Option<(VertexFieldType, u8, bool)> {
+		self.fields.iter()
+			.find(|f| f.location == location)
+			.map(|f| (f.kind, f.len, f.mode == VertexFieldMode::Integer))
+	}
+
+	pub(crate) fn names(&self) -> impl Iterator<Item = (&str, u32)> + '_ {
+		self.fields.iter().filter_map(|f| Some((f.name.as_deref()?, f.location)))
+	}
+
+	pub(crate) fn emit(&self) {
+		for f in &self.fields {
+			if f.mode == VertexFieldMode::Integer {
+				ext::vertex_attrib_i_pointer(
+					f.location,
+					f.len,
+					f.kind.to(),
+					self.stride,
+					f.offset,
+				);
+			} else {
+				lykoi_gl::vertex_attrib_pointer(
+					f.location,
+					f.len,
+					f.kind.to(),
+					f.mode == VertexFieldMode::Normalized,
+					self.stride,
+					f.offset,
+				);
+			}
+			lykoi_gl::enable_vertex_attrib_array(f.location);
+			ext::vertex_attrib_divisor(f.location, self.divisor);
 		}
 	}
 }
+impl Default for VertexFormat {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+
+/**
+types that can make up a [`VertexFormat`] field.
+
+used by `#[derive(VertexLayout)]` to pick the type and length of each field.
+*/
+pub trait AsVertexField {
+	/// the type and amount of components.
+	const FIELD: (VertexFieldType, u8);
+}
+
+macro_rules! impl_as_vertex_field {
+	($kind:ident, $($t:ty),*) => {
+		$(
+			impl AsVertexField for $t {
+				const FIELD: (VertexFieldType, u8) = (VertexFieldType::$kind, 1);
+			}
+			// attributes have at most 4 components, so longer arrays aren't fields.
+			impl_as_vertex_field!(@array $kind, $t, 1, 2, 3, 4);
+		)*
+	};
+	(@array $kind:ident, $t:ty, $($n:literal),*) => {
+		$(
+			impl AsVertexField for [$t; $n] {
+				const FIELD: (VertexFieldType, u8) = (VertexFieldType::$kind, $n);
+			}
+		)*
+	};
+}
+impl_as_vertex_field!(Float, f32);
+impl_as_vertex_field!(Byte, i8);
+impl_as_vertex_field!(UnsignedByte, u8);
+impl_as_vertex_field!(Short, i16);
+impl_as_vertex_field!(UnsignedShort, u16);
+impl_as_vertex_field!(Int, i32);
+impl_as_vertex_field!(UnsignedInt, u32);
+
+impl AsVertexField for cgmath::Vector2<f32> {
+	const FIELD: (VertexFieldType, u8) = (VertexFieldType::Float, 2);
+}
+impl AsVertexField for cgmath::Vector3<f32> {
+	const FIELD: (VertexFieldType, u8) = (VertexFieldType::Float, 3);
+}
+impl AsVertexField for cgmath::Vector4<f32> {
+	const FIELD: (VertexFieldType, u8) = (VertexFieldType::Float, 4);
+}
+impl AsVertexField for cgmath::Point2<f32> {
+	const FIELD: (VertexFieldType, u8) = (VertexFieldType::Float, 2);
+}
+impl AsVertexField for cgmath::Point3<f32> {
+	const FIELD: (VertexFieldType, u8) = (VertexFieldType::Float, 3);
+}
 
+/**
+vertex structs that know their own [`VertexFormat`].
+
+with the `derive` feature, this can be derived for structs whose fields are [`AsVertexField`]:
+
+```
+# #[cfg(feature = "derive")] {
+use lykoi_drive::VertexLayout;
+
+#[repr(C)]
+#[derive(Clone, Copy, VertexLayout)]
+struct Vertex {
+    pos: [f32; 2],
+    #[vertex(normalized)]
+    color: [u8; 4],
+    #[vertex(integer, location = 4, name = "a_bones")]
+    bones: [u16; 2],
+}
+
+assert_eq!(Vertex::format().get_stride(), 16);
+# }
+```
+
+- `#[vertex(normalized)]` maps integers to `0.0..=1.0` / `-1.0..=1.0`.
+- `#[vertex(integer)]` keeps integers as is, for `int`/`uint` inputs.
+  both fail to compile on float fields.
+- `#[vertex(location = N)]` sets the location of a field, the fields after it count up from there.
+- `#[vertex(name = "...")]` names a field after its shader attribute, see [`VertexFormat::named()`].
+- `#[vertex(divisor = N)]` on the struct makes it per-instance data, see [`VertexFormat::divisor()`].
+
+non-generic structs also get an implementation of [`Pod`], which fails to compile if a field
+isn't `Pod` or if the struct has padding.
+*/
+pub trait VertexLayout {
+	fn format() -> VertexFormat;
+}
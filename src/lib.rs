@@ -25,6 +25,9 @@ todo!();
 mod full;
 pub use full::*;
 
+#[cfg(feature = "derive")]
+pub use lykoi_drive_derive::{Std140, Uniforms, VertexLayout};
+
 pub mod extra;
 
 pub use lykoi_gl as gl;
@@ -0,0 +1,126 @@
+/*!
+parsing compile and link logs, with the logs in `fixtures/logs`.
+
+the mesa logs were recorded from llvmpipe. the nvidia and amd logs follow the layout those
+drivers print, including nvidia's per stage headers and amd's trailing summary line.
+*/
+
+use lykoi_drive::{Diagnostic, Error, Severity, ShaderStage};
+
+
+/// parses `log` as the compile log of `stage`.
+fn compile(stage: ShaderStage, log: &str) -> Vec<Diagnostic> {
+	Error::Compile {
+		stage,
+		log: log.to_string(),
+		files: Vec::new(),
+	}.diagnostics()
+}
+
+fn link(log: &str) -> Vec<Diagnostic> {
+	Error::Link(log.to_string()).diagnostics()
+}
+
+/// stage, file, line, column and severity.
+type Location = (Option<ShaderStage>, Option<usize>, Option<usize>, Option<usize>, Severity);
+
+fn locations(list: &[Diagnostic]) -> Vec<Location> {
+	list.iter()
+		.map(|v| (v.stage, v.file, v.line, v.column, v.severity))
+		.collect()
+}
+
+
+#[test]
+fn mesa_compile() {
+	let list = compile(ShaderStage::Fragment, include_str!("fixtures/logs/mesa_compile.log"));
+
+	let frag = Some(ShaderStage::Fragment);
+	assert_eq!(locations(&list), [
+		(frag, Some(0), Some(8), Some(38), Severity::Error),
+		(frag, Some(0), Some(8), Some(15), Severity::Error),
+		(frag, Some(0), Some(9), Some(17), Severity::Warning),
+		(frag, Some(0), Some(9), Some(2), Severity::Error),
+		(frag, Some(0), Some(10), Some(14), Severity::Warning),
+	]);
+	assert_eq!(list[0].message, "`u_tint' undeclared");
+	assert_eq!(list[3].message, "value of type vec3 cannot be assigned to variable of type vec4");
+}
+
+#[test]
+fn mesa_compile_included() {
+	let list = compile(ShaderStage::Fragment, include_str!("fixtures/logs/mesa_include.log"));
+
+	assert_eq!(locations(&list), [
+		(Some(ShaderStage::Fragment), Some(1), Some(4), Some(2), Severity::Error),
+	]);
+	assert_eq!(list[0].message, "`return' with wrong type float, in function `light' returning vec3");
+}
+
+#[test]
+fn mesa_link() {
+	let list = link(include_str!("fixtures/logs/mesa_link.log"));
+
+	assert_eq!(locations(&list), [
+		(None, None, None, None, Severity::Error),
+	]);
+	assert_eq!(
+		list[0].message,
+		"vertex shader output `v_uv' declared as type `vec2', but fragment shader input declared as type `vec3'",
+	);
+}
+
+#[test]
+fn nvidia_compile() {
+	let list = compile(ShaderStage::Fragment, include_str!("fixtures/logs/nvidia_compile.log"));
+
+	let frag = Some(ShaderStage::Fragment);
+	assert_eq!(locations(&list), [
+		(frag, Some(0), Some(8), None, Severity::Error),
+		(frag, Some(0), Some(9), None, Severity::Error),
+		(frag, Some(0), Some(10), None, Severity::Warning),
+	]);
+	assert_eq!(list[0].message, "C1503: undefined variable \"u_tint\"");
+}
+
+#[test]
+fn nvidia_link() {
+	let list = link(include_str!("fixtures/logs/nvidia_link.log"));
+
+	// the stage headers are kept as notes, their underlines are dropped.
+	assert_eq!(locations(&list), [
+		(None, None, None, None, Severity::Note),
+		(None, Some(0), Some(5), None, Severity::Warning),
+		(None, None, None, None, Severity::Note),
+		(None, Some(0), Some(3), None, Severity::Error),
+	]);
+	assert_eq!(list[0].message, "Vertex info");
+	assert_eq!(list[3].message, "C7548: \"v_uv\" has different types in the vertex and fragment shader");
+}
+
+#[test]
+fn amd_compile() {
+	let list = compile(ShaderStage::Vertex, include_str!("fixtures/logs/amd_compile.log"));
+
+	// the summary line isn't a diagnostic of its own.
+	let vert = Some(ShaderStage::Vertex);
+	assert_eq!(locations(&list), [
+		(vert, Some(0), Some(8), None, Severity::Error),
+		(vert, Some(0), Some(9), None, Severity::Error),
+		(vert, Some(0), Some(10), None, Severity::Warning),
+	]);
+	assert_eq!(list[0].message, "'u_tint' : undeclared identifier");
+	assert_eq!(list[2].message, "'unused' : variable is used before being initialized");
+}
+
+#[test]
+fn amd_link() {
+	let list = link(include_str!("fixtures/logs/amd_link.log"));
+
+	assert_eq!(locations(&list), [
+		(None, None, None, None, Severity::Note),
+		(None, None, None, None, Severity::Error),
+		(None, None, None, None, Severity::Error),
+	]);
+	assert_eq!(list[1].message, "error(#277) Symbol 'v_uv' usage doesn't match between two stages");
+}